@@ -0,0 +1,87 @@
+import { access } from "node:fs/promises";
+import { transform } from "@swc/core";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { applySwcTransform } from "./apply-swc-transform";
+
+vi.mock("@swc/core", () => ({
+  transform: vi.fn(async () => ({ code: "transformed" })),
+}));
+
+vi.mock("node:fs/promises", () => ({
+  access: vi.fn(async () => undefined),
+}));
+
+// The plugin package resolves wherever the tests run; `access` decides
+// whether its WASM file is there
+vi.mock("node:module", () => ({
+  createRequire: () => ({ resolve: () => "/plugin/package.json" }),
+}));
+
+const SOURCE = '"use runner";\nexport async function check() {}\n';
+
+/** Makes the plugin's WASM file impossible to find. */
+function removePlugin() {
+  vi.mocked(access).mockRejectedValue(new Error("ENOENT"));
+}
+
+/** Makes every transform that loads the plugin fail. */
+function breakPlugin() {
+  vi.mocked(transform).mockImplementation(async (_source, options) => {
+    if (options?.jsc?.experimental?.plugins) {
+      throw new Error("failed to invoke plugin");
+    }
+    return { code: "transformed" };
+  });
+}
+
+describe("applySwcTransform", () => {
+  beforeEach(() => {
+    vi.spyOn(console, "warn").mockImplementation(() => undefined);
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+    vi.mocked(access).mockReset();
+    vi.mocked(transform).mockReset();
+  });
+
+  it("transforms without the plugin when it is missing", async () => {
+    removePlugin();
+    vi.mocked(transform).mockResolvedValue({ code: "transformed" });
+
+    const result = await applySwcTransform("runner.ts", SOURCE);
+
+    expect(result.code).toBe("transformed");
+  });
+
+  it("fails client builds when the plugin is missing", async () => {
+    removePlugin();
+
+    await expect(
+      applySwcTransform("runner.ts", SOURCE, { mode: "client" })
+    ).rejects.toThrow('@runners/swc-plugin is required for mode "client"');
+    expect(transform).not.toHaveBeenCalled();
+  });
+
+  it("retries without the plugin when it fails", async () => {
+    breakPlugin();
+
+    const result = await applySwcTransform("runner.ts", SOURCE);
+
+    expect(result).toEqual({
+      code: "transformed",
+      runnerManifest: {},
+      map: undefined,
+    });
+    expect(transform).toHaveBeenCalledTimes(2);
+  });
+
+  it("fails client builds when the plugin fails", async () => {
+    breakPlugin();
+
+    await expect(
+      applySwcTransform("runner.ts", SOURCE, { mode: "client" })
+    ).rejects.toThrow("failed to invoke plugin");
+    expect(transform).toHaveBeenCalledTimes(1);
+  });
+});
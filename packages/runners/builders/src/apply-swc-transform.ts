@@ -13,6 +13,16 @@ export type RunnerManifest = {
       };
     };
   };
+  /**
+   * Runner and schema metadata for this file, in the same shape as a
+   * `runner-schemas.json` file entry. Only present with `extractMetadata`.
+   */
+  metadata?: {
+    file: string;
+    runners: { name: string; line: number; input_schema?: string }[];
+    schemas: { name: string; runner_name: string | null; line: number }[];
+    partial: boolean;
+  };
 };
 
 export type SwcTransformOptions = {
@@ -21,8 +31,56 @@ export type SwcTransformOptions = {
   baseUrl?: string;
   minify?: boolean;
   sourceMaps?: boolean;
+  // collect runner/schema metadata in the plugin pass (see RunnerManifest.metadata)
+  extractMetadata?: boolean;
+  // calls whose last argument is a runner function (plugin default: ["defineRunner"])
+  runnerFactories?: string[];
+  // validate runner input against its Standard Schema before the body runs
+  validateInput?: boolean;
+  // time runners and normalize their results and thrown errors
+  normalizeResults?: boolean;
+  // "client" replaces runner bodies with stubs that call executeUrl
+  mode?: "server" | "client";
+  // where client stubs POST { runners: [{ name, input }] } (plugin default: "/api/runner/execute", browser only)
+  executeUrl?: string;
+  // append `export const __runners = { [runnerId]: { fn, name, file, line, inputSchema } }`
+  registerRunners?: boolean;
+  // attach { file, line, column, runnerId } to runners as `__runnerLocation`
+  sourceLocations?: boolean;
 };
 
+/** The `SwcTransformOptions` that configure the runners SWC plugin itself. */
+export type RunnerTransformOptions = Pick<
+  SwcTransformOptions,
+  | "extractMetadata"
+  | "runnerFactories"
+  | "validateInput"
+  | "normalizeResults"
+  | "mode"
+  | "executeUrl"
+  | "registerRunners"
+  | "sourceLocations"
+>;
+
+/**
+ * Picks the runners plugin options out of a larger options object, so they
+ * can be passed on to `applySwcTransform` unchanged.
+ */
+export function pickRunnerTransformOptions(
+  options: RunnerTransformOptions
+): RunnerTransformOptions {
+  return {
+    extractMetadata: options.extractMetadata,
+    runnerFactories: options.runnerFactories,
+    validateInput: options.validateInput,
+    normalizeResults: options.normalizeResults,
+    mode: options.mode,
+    executeUrl: options.executeUrl,
+    registerRunners: options.registerRunners,
+    sourceLocations: options.sourceLocations,
+  };
+}
+
 const RUNNER_MANIFEST_REGEX = /\/\*\*__internal_runners({.*?})\*\//s;
 
 /**
@@ -45,6 +103,8 @@ export async function applySwcTransform(
   // Determine if this is a TypeScript file
   const isTypeScript = filename.endsWith(".ts") || filename.endsWith(".tsx");
   const isTsx = filename.endsWith(".tsx");
+  // SWC parses every file as a module unless told otherwise
+  const isModule = !filename.endsWith(".cjs");
 
   // Resolve SWC plugin path
   let swcPluginPath: string | undefined;
@@ -86,6 +146,13 @@ export async function applySwcTransform(
       }
     }
   } catch (error) {
+    // Without the plugin, client builds would bundle the runner bodies
+    if (options.mode === "client") {
+      throw new Error(
+        `[runners/builders] @runners/swc-plugin is required for mode "client": ${error instanceof Error ? error.message : String(error)}`,
+        { cause: error }
+      );
+    }
     // Plugin not found, continue without it
     // eslint-disable-next-line no-console
     console.warn(
@@ -98,6 +165,7 @@ export async function applySwcTransform(
     const result = await transform(source, {
       filename,
       swcrc: false,
+      isModule,
       jsc: {
         parser: {
           syntax: isTypeScript ? "typescript" : "ecmascript",
@@ -106,7 +174,25 @@ export async function applySwcTransform(
         target: "es2022",
         experimental: swcPluginPath
           ? {
-              plugins: [[swcPluginPath, {}]],
+              plugins: [
+                [
+                  swcPluginPath,
+                  {
+                    ...(options.extractMetadata && { extractMetadata: true }),
+                    ...(options.runnerFactories && {
+                      runnerFactories: options.runnerFactories,
+                    }),
+                    ...(options.validateInput && { validateInput: true }),
+                    ...(options.normalizeResults && { normalizeResults: true }),
+                    ...(options.mode && { mode: options.mode }),
+                    ...(options.executeUrl && {
+                      executeUrl: options.executeUrl,
+                    }),
+                    ...(options.registerRunners && { registerRunners: true }),
+                    ...(options.sourceLocations && { sourceLocations: true }),
+                  },
+                ],
+              ],
             }
           : undefined,
         ...(options.paths && options.baseUrl
@@ -134,8 +220,9 @@ export async function applySwcTransform(
       map: result.map,
     };
   } catch (error) {
-    // If plugin fails, try without it
-    if (swcPluginPath && error instanceof Error) {
+    // If plugin fails, try without it, except for client builds, which
+    // would then bundle the runner bodies
+    if (swcPluginPath && options.mode !== "client" && error instanceof Error) {
       const errorMsg = error.message.toLowerCase();
       // Check for plugin-related errors (case-insensitive)
       if (
@@ -154,6 +241,7 @@ export async function applySwcTransform(
         const result = await transform(source, {
           filename,
           swcrc: false,
+          isModule,
           jsc: {
             parser: {
               syntax: isTypeScript ? "typescript" : "ecmascript",
@@ -7,7 +7,11 @@ import {
 import chalk from "chalk";
 import { build, context } from "esbuild";
 import { glob } from "glob";
-import type { RunnerManifest } from "./apply-swc-transform";
+import {
+  pickRunnerTransformOptions,
+  type RunnerManifest,
+  type RunnerTransformOptions,
+} from "./apply-swc-transform";
 import { createDiscoverRunnersPlugin } from "./discover-plugin";
 import { createSwcPlugin } from "./swc-esbuild-plugin";
 import type { RunnerBuilderConfig } from "./types";
@@ -40,6 +44,7 @@ function getBuildOptions(options: {
   runnerManifest?: RunnerManifest;
   entriesToBundle?: string[];
   outdir?: string;
+  transform?: RunnerTransformOptions;
 }): {
   minify: boolean;
   sourcemap: boolean | "inline";
@@ -66,6 +71,7 @@ function getBuildOptions(options: {
       entriesToBundle: options.entriesToBundle,
       outdir: options.outdir,
       runnerManifest: options.runnerManifest,
+      ...options.transform,
     }),
   ];
 
@@ -410,6 +416,7 @@ export class BaseBuilder {
       runnerManifest,
       entriesToBundle: runnerFiles, // Only bundle files with directives
       outdir: dirname(outfile),
+      transform: pickRunnerTransformOptions(this.config),
     });
 
     // Bundle with esbuild and SWC transforms
@@ -579,6 +586,7 @@ export class BaseBuilder {
       runnerManifest,
       entriesToBundle: runnerFiles, // Only bundle files with directives
       outdir: dirname(outfile),
+      transform: pickRunnerTransformOptions(this.config),
       minify: false, // Don't minify in watch mode
       sourcemap: EMIT_SOURCEMAPS_FOR_DEBUGGING ? "inline" : false,
     });
@@ -1,5 +1,6 @@
 export type {
   RunnerManifest,
+  RunnerTransformOptions,
   SwcTransformOptions,
 } from "./apply-swc-transform";
 export { applySwcTransform } from "./apply-swc-transform";
@@ -0,0 +1,91 @@
+import { mkdtemp, rm, writeFile } from "node:fs/promises";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import type { OnLoadArgs, OnLoadResult, PluginBuild } from "esbuild";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { applySwcTransform } from "./apply-swc-transform";
+import { createSwcPlugin, type SwcPluginOptions } from "./swc-esbuild-plugin";
+
+vi.mock("./apply-swc-transform", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("./apply-swc-transform")>()),
+  applySwcTransform: vi.fn(async () => ({ code: "", runnerManifest: {} })),
+}));
+
+type OnLoadCallback = (args: OnLoadArgs) => Promise<OnLoadResult>;
+
+/** Runs the plugin's onLoad hook for `path` inside `workingDir`. */
+async function load(
+  options: SwcPluginOptions,
+  workingDir: string,
+  path: string
+): Promise<OnLoadResult> {
+  let onLoad: OnLoadCallback | undefined;
+  const build = {
+    initialOptions: { absWorkingDir: workingDir },
+    onResolve: () => undefined,
+    onLoad: (_options: unknown, callback: OnLoadCallback) => {
+      onLoad = callback;
+    },
+  } as unknown as PluginBuild;
+
+  await createSwcPlugin(options).setup(build);
+  if (!onLoad) {
+    throw new Error("plugin did not register an onLoad hook");
+  }
+  return onLoad({ path } as OnLoadArgs);
+}
+
+describe("createSwcPlugin", () => {
+  let workingDir: string;
+
+  beforeEach(async () => {
+    workingDir = await mkdtemp(join(tmpdir(), "runners-builders-"));
+    await writeFile(join(workingDir, "runner.ts"), '"use runner";\n');
+    vi.mocked(applySwcTransform).mockClear();
+  });
+
+  afterEach(async () => {
+    await rm(workingDir, { recursive: true, force: true });
+  });
+
+  it("forwards the runner transform options to applySwcTransform", async () => {
+    await load(
+      {
+        extractMetadata: true,
+        runnerFactories: ["createRunner"],
+        validateInput: true,
+        normalizeResults: true,
+        mode: "client",
+        executeUrl: "https://example.com/api/runner/execute",
+        registerRunners: true,
+        sourceLocations: true,
+      },
+      workingDir,
+      join(workingDir, "runner.ts")
+    );
+
+    expect(applySwcTransform).toHaveBeenCalledWith(
+      "runner.ts",
+      '"use runner";\n',
+      expect.objectContaining({
+        extractMetadata: true,
+        runnerFactories: ["createRunner"],
+        validateInput: true,
+        normalizeResults: true,
+        mode: "client",
+        executeUrl: "https://example.com/api/runner/execute",
+        registerRunners: true,
+        sourceLocations: true,
+      })
+    );
+  });
+
+  it("leaves the plugin defaults alone when no options are set", async () => {
+    await load({}, workingDir, join(workingDir, "runner.ts"));
+
+    const options = vi.mocked(applySwcTransform).mock.calls[0]?.[2];
+    expect(options?.mode).toBeUndefined();
+    expect(options?.validateInput).toBeUndefined();
+    expect(options?.executeUrl).toBeUndefined();
+  });
+});
@@ -3,7 +3,12 @@ import { relative } from "node:path";
 import { promisify } from "node:util";
 import enhancedResolveOriginal from "enhanced-resolve";
 import type { Plugin } from "esbuild";
-import { applySwcTransform, type RunnerManifest } from "./apply-swc-transform";
+import {
+  applySwcTransform,
+  pickRunnerTransformOptions,
+  type RunnerManifest,
+  type RunnerTransformOptions,
+} from "./apply-swc-transform";
 import { jsTsRegex, parentHasChild } from "./discover-plugin";
 
 const PATH_SEPARATOR_REGEX = /\\/g;
@@ -19,7 +24,7 @@ export type SwcPluginOptions = {
   entriesToBundle?: string[];
   outdir?: string;
   runnerManifest?: RunnerManifest;
-};
+} & RunnerTransformOptions;
 
 const NODE_RESOLVE_OPTIONS = {
   dependencyType: "commonjs",
@@ -219,6 +224,7 @@ export function createSwcPlugin(options: SwcPluginOptions = {}): Plugin {
             baseUrl: options.tsBaseUrl,
             minify: options.minify,
             sourceMaps: options.sourceMaps,
+            ...pickRunnerTransformOptions(options),
           });
 
           // Merge manifest if provided
@@ -1,6 +1,8 @@
+import type { RunnerTransformOptions } from "./apply-swc-transform";
+
 export type RunnerBuilderConfig = {
   workingDir: string;
   watch?: boolean;
   patterns?: string[];
   outDir: string;
-};
+} & RunnerTransformOptions;
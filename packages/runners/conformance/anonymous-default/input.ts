@@ -0,0 +1,10 @@
+"use runner";
+
+import { z } from "zod";
+
+export const defaultInputSchema = z.object({ url: z.string() });
+
+export default async (ctx, input) => {
+  ctx.log(`Checking ${input.url}`);
+  return { name: "default", status: "pass" as const };
+};
@@ -0,0 +1,11 @@
+/*__runners_transformed*/ import { z } from "zod";
+export const defaultInputSchema = z.object({
+    url: z.string()
+});
+export default async (ctx, input)=>{
+    ctx.log(`Checking ${input.url}`);
+    return {
+        name: "default",
+        status: "pass" as const
+    };
+};
@@ -0,0 +1,22 @@
+/*__runners_transformed*/ import type { Runner } from "runners";
+import { z } from "zod";
+export const checkTitleInputSchema = z.object({
+    url: z.string()
+});
+export const checkTitle: Runner<typeof checkTitleInputSchema> = async (ctx, input)=>{
+    return {
+        name: "checkTitle",
+        status: "pass"
+    };
+};
+export const checkFooter = async function(ctx) {
+    return {
+        name: "checkFooter",
+        status: "pass"
+    };
+};
+// No directive, so not a runner
+export const helper = async (value: string)=>value.trim();
+export const shorthand = async ()=>({
+        status: "pass"
+    });
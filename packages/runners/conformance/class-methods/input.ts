@@ -0,0 +1,16 @@
+"use runner";
+
+export class Suite {
+  async run(ctx) {
+    "use runner";
+    return { name: "run", status: "pass" };
+  }
+
+  static async setup() {
+    return true;
+  }
+}
+
+export async function suiteRunner(ctx) {
+  return new Suite().run(ctx);
+}
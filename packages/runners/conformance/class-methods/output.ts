@@ -0,0 +1,14 @@
+/*__runners_transformed*/ export class Suite {
+    async run(ctx) {
+        return {
+            name: "run",
+            status: "pass"
+        };
+    }
+    static async setup() {
+        return true;
+    }
+}
+export async function suiteRunner(ctx) {
+    return new Suite().run(ctx);
+}
@@ -0,0 +1,7 @@
+import type { RunnerContext } from "runners";
+
+export default async function checkHomepage(ctx: RunnerContext) {
+  "use runner";
+  ctx.log("Checking homepage");
+  return { name: "checkHomepage", status: "pass" as const };
+}
@@ -0,0 +1,8 @@
+/*__runners_transformed*/ import type { RunnerContext } from "runners";
+export default async function checkHomepage(ctx: RunnerContext) {
+    ctx.log("Checking homepage");
+    return {
+        name: "checkHomepage",
+        status: "pass" as const
+    };
+}
@@ -0,0 +1,25 @@
+/*__runners_transformed*/ "use client";
+"use strict";
+// Comments don't end the prologue
+export async function afterStrict(ctx) {
+    "use strict";
+    return {
+        name: "afterStrict",
+        status: "pass"
+    };
+}
+export async function notAPrologue(ctx) {
+    const ready = true;
+    "use runner";
+    return {
+        name: "notAPrologue",
+        status: ready ? "pass" : "fail"
+    };
+}
+export const parenthesized = async (ctx)=>{
+    ("use runner");
+    return {
+        name: "parenthesized",
+        status: "pass"
+    };
+};
@@ -0,0 +1,37 @@
+"use runner";
+
+import { z } from "zod";
+
+export async function checkLinks(ctx) {
+  // Helpers declared inside a runner are not runners
+  async function fetchLink(url: string) {
+    return ctx.page.goto(url);
+  }
+  const visit = async (url: string) => fetchLink(url);
+  const linkSchema = z.object({ href: z.string() });
+  return { name: "checkLinks", status: "pass" };
+}
+
+export namespace Billing {
+  export const chargeInputSchema = z.object({ amount: z.number() });
+
+  export async function charge(ctx) {
+    return { name: "charge", status: "pass" };
+  }
+
+  // Not exported from the namespace
+  async function refund(ctx) {
+    return { name: "refund", status: "pass" };
+  }
+}
+
+// Not exported from the module
+namespace Internal {
+  export async function audit(ctx) {
+    return { name: "audit", status: "pass" };
+  }
+}
+
+declare namespace Ambient {
+  export function check(ctx): Promise<unknown>;
+}
@@ -0,0 +1,24 @@
+/*__runners_transformed*/ import { z } from "zod";
+export { fetchUser } from "./users";
+export * from "./shared";
+async function loginFlow(ctx) {
+    return {
+        name: "loginFlow",
+        status: "pass"
+    };
+}
+const logoutFlow = async (ctx)=>{
+    return {
+        name: "logoutFlow",
+        status: "pass"
+    };
+};
+function formatUser(user: {
+    name: string;
+}) {
+    return user.name;
+}
+export const loginFlowInputSchema = z.object({
+    username: z.string()
+});
+export { loginFlow, logoutFlow as logout, formatUser };
@@ -0,0 +1,16 @@
+"use runner";
+
+import { render } from "./render";
+
+const identity = <T,>(value: T) => value;
+
+export function Banner({ title }: { title: string }) {
+  return <p className="banner">{title}</p>;
+}
+
+export async function bannerRenders(ctx) {
+  const html = await render(<Banner title={identity("Hello")} />);
+  return { name: "bannerRenders", status: html.includes("Hello") ? "pass" : "fail" };
+}
+
+export const bannerRendersSchema = {};
@@ -0,0 +1,5 @@
+"use rnuner";
+
+export async function checkPricing(ctx) {
+  return { name: "checkPricing", status: "pass" };
+}
@@ -0,0 +1,6 @@
+/*__runners_transformed*/ export async function checkPricing(ctx) {
+    return {
+        name: "checkPricing",
+        status: "pass"
+    };
+}
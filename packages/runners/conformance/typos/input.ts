@@ -0,0 +1,20 @@
+"use runnr";
+
+export async function checkLogin(ctx) {
+  "Use runner";
+  return { name: "checkLogin", status: "pass" };
+}
+
+export async function checkLogout(ctx) {
+  "use runner";
+  return { name: "checkLogout", status: "pass" };
+}
+
+export async function checkSignup(ctx) {
+  "use-runner";
+  return { name: "checkSignup", status: "pass" };
+}
+
+export async function saveSettings() {
+  "use server";
+}
@@ -0,0 +1,21 @@
+/*__runners_transformed*/ export async function checkLogin(ctx) {
+    return {
+        name: "checkLogin",
+        status: "pass"
+    };
+}
+export async function checkLogout(ctx) {
+    return {
+        name: "checkLogout",
+        status: "pass"
+    };
+}
+export async function checkSignup(ctx) {
+    return {
+        name: "checkSignup",
+        status: "pass"
+    };
+}
+export async function saveSettings() {
+    "use server";
+}
@@ -0,0 +1,38 @@
+/*__runners_transformed*/ import type { Runner } from "runners";
+import { defineRunner } from "runners";
+import { z } from "zod";
+export const checkTitle = (async (ctx)=>{
+    return {
+        name: "checkTitle",
+        status: "pass"
+    };
+}) satisfies Runner;
+export const checkFooter = async function(ctx) {
+    return {
+        name: "checkFooter",
+        status: "pass"
+    };
+} as Runner;
+export const urlSchema = z.object({
+    url: z.string()
+});
+// The factory's schema argument is matched to the runner
+export const checkLinks = defineRunner(urlSchema, async (ctx, input)=>{
+    return {
+        name: "checkLinks",
+        status: "pass"
+    };
+});
+// Not a known factory
+export const memoized = memoize(async (ctx)=>{
+    return {
+        name: "memoized",
+        status: "pass"
+    };
+});
+export default defineRunner(async function(ctx) {
+    return {
+        name: "default",
+        status: "pass"
+    };
+});
@@ -0,0 +1,97 @@
+import { readFile } from "node:fs/promises";
+import type { RunnerResult } from "@runners/core";
+import { afterEach, beforeAll, describe, expect, it, vi } from "vitest";
+import { createOrpcRunnerHandler } from "./handler";
+import type { RunnerSchemaInfo } from "./schema-discovery";
+
+type Stub = (
+  endpoint: string,
+  name: string
+) => (ctx: unknown, input?: unknown) => Promise<RunnerResult<unknown>>;
+
+const EXECUTE_URL = "https://runners.test/api/runner/execute";
+
+/**
+ * The helper `@runners/swc-plugin` puts in place of runner bodies with
+ * `mode: "client"`, loaded from the plugin's source.
+ */
+async function loadClientStub(): Promise<Stub> {
+  const source = await readFile(
+    new URL("../../swc-plugin-runners/src/helpers/execute.js", import.meta.url),
+    "utf8"
+  );
+  return new Function(`${source}\nreturn __runners_execute;`)() as Stub;
+}
+
+describe("client mode stub", () => {
+  let execute: Stub;
+  let handler: (req: Request) => Promise<Response>;
+
+  beforeAll(async () => {
+    execute = await loadClientStub();
+    handler = await createOrpcRunnerHandler({
+      runners: {
+        echo: async (_ctx, input) => ({
+          name: "echo",
+          status: "pass",
+          details: input,
+        }),
+      },
+      // Skips schema discovery, which would scan the working directory
+      schemas: new Map<string, RunnerSchemaInfo>([["echo", { name: "echo" }]]),
+    });
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  function serveWithHandler() {
+    vi.stubGlobal("fetch", (url: URL, init: RequestInit) =>
+      handler(new Request(url, init))
+    );
+  }
+
+  it("runs the runner through the execute handler", async () => {
+    serveWithHandler();
+
+    const result = await execute(EXECUTE_URL, "echo")({}, { url: "x" });
+
+    expect(result).toMatchObject({
+      name: "echo",
+      status: "pass",
+      details: { url: "x" },
+    });
+  });
+
+  it("returns an error result for a runner the server doesn't have", async () => {
+    serveWithHandler();
+
+    const result = await execute(EXECUTE_URL, "missing")({});
+
+    expect(result.status).toBe("error");
+    expect(result.name).toBe("missing");
+    expect(result.errorMessage).toMatch(/Runner request failed with status/);
+  });
+
+  it("returns an error result when fetch throws", async () => {
+    vi.stubGlobal("fetch", () => Promise.reject(new TypeError("fetch failed")));
+
+    const result = await execute(EXECUTE_URL, "echo")({});
+
+    expect(result).toEqual({
+      name: "echo",
+      status: "error",
+      errorMessage: "fetch failed",
+    });
+  });
+
+  it("requires an absolute URL outside a browser", async () => {
+    serveWithHandler();
+
+    const result = await execute("/api/runner/execute", "echo")({});
+
+    expect(result.status).toBe("error");
+    expect(result.errorMessage).toMatch(/absolute URL/);
+  });
+});
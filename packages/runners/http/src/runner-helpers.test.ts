@@ -0,0 +1,118 @@
+import { readFile } from "node:fs/promises";
+import type { RunnerResult } from "@runners/core";
+import { beforeAll, describe, expect, it } from "vitest";
+
+type Run = (ctx: unknown, input?: unknown) => Promise<RunnerResult<unknown>>;
+
+/**
+ * A helper `@runners/swc-plugin` wraps runners in, loaded from the plugin's
+ * source.
+ */
+async function loadHelper<T>(file: string, name: string): Promise<T> {
+  const source = await readFile(
+    new URL(`../../swc-plugin-runners/src/helpers/${file}`, import.meta.url),
+    "utf8"
+  );
+  return new Function(`${source}\nreturn ${name};`)() as T;
+}
+
+describe("input validation helper", () => {
+  type ValidateInput = (runnerId: string, schema: unknown, run: Run) => Run;
+
+  let validateInput: ValidateInput;
+
+  const echo: Run = async (_ctx, input) => ({
+    name: "echo",
+    status: "pass",
+    details: input,
+  });
+
+  beforeAll(async () => {
+    validateInput = await loadHelper<ValidateInput>(
+      "validate_input.js",
+      "__runners_validateInput"
+    );
+  });
+
+  it("runs the runner with the validated input", async () => {
+    const schema = {
+      "~standard": {
+        validate: (value: unknown) => ({ value: { url: String(value) } }),
+      },
+    };
+
+    const result = await validateInput("echo", schema, echo)({}, "x");
+
+    expect(result).toMatchObject({ status: "pass", details: { url: "x" } });
+  });
+
+  it.each([
+    ["undefined", undefined],
+    ["a plain object", { parse: () => ({}) }],
+    ["a schema without validate", { "~standard": { version: 1 } }],
+  ])("returns an error result when the schema is %s", async (_, schema) => {
+    const result = await validateInput("echo", schema, echo)({}, "x");
+
+    expect(result).toMatchObject({
+      name: "echo",
+      status: "error",
+      errorMessage: expect.stringContaining("~standard.validate"),
+    });
+  });
+});
+
+describe("result normalization helper", () => {
+  type NormalizeResult = (
+    runnerId: string,
+    run: (...args: unknown[]) => Promise<unknown>
+  ) => Run;
+
+  let normalizeResult: NormalizeResult;
+
+  beforeAll(async () => {
+    normalizeResult = await loadHelper<NormalizeResult>(
+      "normalize_result.js",
+      "__runners_normalizeResult"
+    );
+  });
+
+  it.each([
+    ["a number", 42],
+    ["a string", "done"],
+    ["null", null],
+  ])("wraps %s in a passing result", async (_, output) => {
+    const result = await normalizeResult(
+      "runners/a.ts#check",
+      async () => output
+    )({});
+
+    expect(result).toMatchObject({
+      name: "runners/a.ts#check",
+      status: "pass",
+      details: { output },
+      durationMs: expect.any(Number),
+    });
+  });
+
+  it("keeps the duration a runner reports", async () => {
+    const result = await normalizeResult("runners/a.ts#check", async () => ({
+      name: "check",
+      status: "pass",
+      durationMs: 1234,
+    }))({});
+
+    expect(result).toEqual({ name: "check", status: "pass", durationMs: 1234 });
+  });
+
+  it("times runners that don't report a duration", async () => {
+    const result = await normalizeResult("runners/a.ts#check", async () => ({
+      status: "fail",
+    }))({});
+
+    expect(result).toMatchObject({
+      name: "runners/a.ts#check",
+      status: "fail",
+      durationMs: expect.any(Number),
+    });
+  });
+});
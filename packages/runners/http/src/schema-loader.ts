@@ -1,4 +1,5 @@
 import { readFile } from "node:fs/promises";
+import { resolve } from "node:path";
 import { pathToFileURL } from "node:url";
 import { z } from "zod";
 import type { RunnerSchemaInfo } from "./schema-discovery";
@@ -21,12 +22,73 @@ const FileMetadataSchema = z.object({
   file: z.string(),
   runners: z.array(RunnerInfoSchema),
   schemas: z.array(SchemaInfoSchema),
+  partial: z.boolean().optional(),
 });
 
-const MetadataSchema = z.array(FileMetadataSchema);
+/**
+ * Envelope format version this loader understands
+ */
+const METADATA_FORMAT_VERSION = 2;
+
+const MetadataSchema = z.object({
+  version: z.literal(METADATA_FORMAT_VERSION),
+  generator: z.object({
+    name: z.string(),
+    version: z.string(),
+  }),
+  root: z.string(),
+  files: z.array(FileMetadataSchema),
+});
+
+/**
+ * Bare array written by `--format-version 1` (and extractor releases before
+ * the envelope). Paths are resolved against the current working directory.
+ */
+const LegacyMetadataSchema = z.array(FileMetadataSchema);
 
 type Metadata = z.infer<typeof MetadataSchema>;
 
+function parseMetadata(parsed: unknown): Metadata {
+  if (Array.isArray(parsed)) {
+    return {
+      version: METADATA_FORMAT_VERSION,
+      generator: { name: "unknown", version: "legacy" },
+      root: process.cwd(),
+      files: LegacyMetadataSchema.parse(parsed),
+    };
+  }
+
+  if (
+    parsed &&
+    typeof parsed === "object" &&
+    "version" in parsed &&
+    typeof parsed.version === "number" &&
+    parsed.version > METADATA_FORMAT_VERSION
+  ) {
+    throw new Error(
+      `Unsupported schema metadata version ${parsed.version} (expected ${METADATA_FORMAT_VERSION}); upgrade @runners/http`
+    );
+  }
+
+  return MetadataSchema.parse(parsed);
+}
+
+/**
+ * Look up an export by its metadata name; namespace members are reported
+ * as `Namespace.member`
+ */
+function resolveExport(module: Record<string, unknown>, name: string): unknown {
+  return name
+    .split(".")
+    .reduce<unknown>(
+      (value, key) =>
+        value && typeof value === "object"
+          ? (value as Record<string, unknown>)[key]
+          : undefined,
+      module
+    );
+}
+
 /**
  * Load pre-extracted schema metadata from build-time extraction
  */
@@ -50,8 +112,8 @@ export async function loadBuildTimeSchemas(
     // Parse JSON
     const parsed = JSON.parse(metadataContent);
 
-    // Validate with Zod schema
-    metadata = MetadataSchema.parse(parsed);
+    // Validate with Zod schema (accepts the legacy bare array as well)
+    metadata = parseMetadata(parsed);
   } catch (error) {
     if (error instanceof z.ZodError) {
       throw new Error(
@@ -70,9 +132,17 @@ export async function loadBuildTimeSchemas(
     const schemas = new Map<string, RunnerSchemaInfo>();
 
     // Import actual Zod schemas from the files
-    for (const fileMetadata of metadata) {
+    for (const fileMetadata of metadata.files) {
+      if (fileMetadata.partial) {
+        console.warn(
+          `[runners/http] ${fileMetadata.file} had syntax errors at build time; some runners may be missing`
+        );
+      }
       try {
-        const moduleUrl = pathToFileURL(fileMetadata.file).href;
+        // File paths are relative to the extraction root
+        const moduleUrl = pathToFileURL(
+          resolve(metadata.root, fileMetadata.file)
+        ).href;
         const module = await import(moduleUrl);
 
         // Map runners to their schemas
@@ -86,7 +156,7 @@ export async function loadBuildTimeSchemas(
           );
 
           if (schemaInfo) {
-            const schemaExport = module[schemaInfo.name];
+            const schemaExport = resolveExport(module, schemaInfo.name);
             if (schemaExport && typeof schemaExport === "object") {
               const isZodSchema =
                 "_def" in schemaExport || "_zod" in schemaExport;
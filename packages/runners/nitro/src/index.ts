@@ -1,5 +1,6 @@
 import { execSync } from "node:child_process";
-import { existsSync } from "node:fs";
+import { existsSync, mkdirSync, writeFileSync } from "node:fs";
+import { createRequire } from "node:module";
 import { dirname } from "node:path";
 import { fileURLToPath } from "node:url";
 import type { Nitro, NitroModule } from "nitro/types";
@@ -90,11 +91,43 @@ export default {
   },
 } satisfies NitroModule;
 
+/**
+ * Load the in-process extractor binding from the project, if it is installed.
+ */
+function loadExtractorBinding():
+  | {
+      extract: (patterns: string[], options: { cwd: string }) => unknown;
+    }
+  | undefined {
+  try {
+    const require = createRequire(join(process.cwd(), "package.json"));
+    return require("@runners/schema-extractor-node");
+  } catch {
+    return;
+  }
+}
+
 function extractSchemaMetadata(
   patterns: string[],
   outputPath: string,
   cwd: string
 ): void {
+  // Prefer the Node binding: no process spawn per build
+  const binding = loadExtractorBinding();
+  if (binding) {
+    try {
+      const extraction = binding.extract(patterns, { cwd });
+      mkdirSync(dirname(outputPath), { recursive: true });
+      writeFileSync(outputPath, JSON.stringify(extraction, null, 2));
+      return;
+    } catch (error) {
+      console.warn(
+        "[runners/nitro] Schema extractor binding failed, trying the binary:",
+        error instanceof Error ? error.message : String(error)
+      );
+    }
+  }
+
   try {
     // Try to find schema-extractor binary
     // Check both Unix and Windows paths (.exe extension)
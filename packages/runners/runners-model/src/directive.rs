@@ -0,0 +1,84 @@
+/// The directive that marks a module or function as containing runners.
+pub const USE_RUNNER_DIRECTIVE: &str = "use runner";
+
+/// Directives used by other tools. A string closer to one of these than to
+/// `"use runner"` is left alone rather than reported as a typo.
+pub const KNOWN_DIRECTIVES: &[&str] = &[
+    "use strict",
+    "use client",
+    "use server",
+    "use cache",
+    "use workflow",
+    "use step",
+];
+
+/// Default maximum edit distance for [`is_directive_typo`].
+pub const DEFAULT_TYPO_THRESHOLD: usize = 1;
+
+/// Whether a directive string is exactly `"use runner"`.
+pub fn is_use_runner_directive(value: &str) -> bool {
+    value == USE_RUNNER_DIRECTIVE
+}
+
+/// Whether a directive string looks like a misspelled `"use runner"`.
+///
+/// The value is normalized first (case, surrounding whitespace, and runs of
+/// whitespace, `-` or `_` between words), so `"Use Runner"` and `"use-runner"`
+/// are always typos. Otherwise it must be within `max_distance` edits
+/// (Damerau-Levenshtein: insertions, deletions, substitutions and adjacent
+/// transpositions) of `"use runner"`, and strictly closer to it than to any of
+/// [`KNOWN_DIRECTIVES`].
+pub fn is_directive_typo(value: &str, max_distance: usize) -> bool {
+    if is_use_runner_directive(value) || KNOWN_DIRECTIVES.contains(&value) {
+        return false;
+    }
+
+    let normalized = normalize_directive(value);
+    let distance = damerau_levenshtein(&normalized, USE_RUNNER_DIRECTIVE);
+    if distance > max_distance {
+        return false;
+    }
+
+    KNOWN_DIRECTIVES
+        .iter()
+        .all(|known| damerau_levenshtein(&normalized, known) > distance)
+}
+
+/// Lowercase, trim, and collapse whitespace, `-` and `_` separators to one space.
+pub fn normalize_directive(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-' || c == '_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Edit distance counting adjacent transpositions as one edit (optimal
+/// string alignment variant).
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between a[..i] and b[..j]
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    distances[0] = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
@@ -0,0 +1,4 @@
+/// Stable ID of a runner: its file (relative, forward slashes) and export name.
+pub fn runner_id(file: &str, name: &str) -> String {
+    format!("{file}#{name}")
+}
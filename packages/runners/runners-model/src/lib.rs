@@ -0,0 +1,25 @@
+//! Rules for what counts as a runner, shared by `swc_plugin_runners` and
+//! `schema_extractor`.
+//!
+//! Both tools parse source with different ASTs (SWC and tree-sitter), so this
+//! crate only works on the facts they pull out of it: directive strings,
+//! function flags and export names. Keeping the decisions here means the
+//! build transform and the metadata extractor can't disagree about which
+//! functions are runners.
+
+mod directive;
+mod id;
+mod naming;
+mod runner;
+mod types;
+
+pub use directive::{
+    damerau_levenshtein, is_directive_typo, is_use_runner_directive, normalize_directive,
+    DEFAULT_TYPO_THRESHOLD, KNOWN_DIRECTIVES, USE_RUNNER_DIRECTIVE,
+};
+pub use id::runner_id;
+pub use naming::{
+    is_schema_name, schema_runner_name, DEFAULT_EXPORT_NAME, DEFAULT_RUNNER_FACTORIES,
+};
+pub use runner::RunnerCandidate;
+pub use types::{RunnerInfo, SchemaInfo, SchemaMetadata};
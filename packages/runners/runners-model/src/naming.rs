@@ -0,0 +1,25 @@
+use crate::types::RunnerInfo;
+
+/// Name an anonymous default-exported runner is reported under, matching the
+/// key it has on the imported module object.
+pub const DEFAULT_EXPORT_NAME: &str = "default";
+
+/// Whether an exported variable is treated as a schema (its name contains
+/// "schema", case-insensitively).
+pub fn is_schema_name(name: &str) -> bool {
+    name.to_lowercase().contains("schema")
+}
+
+/// Calls that wrap a runner function, e.g. `defineRunner(schema, async () => {})`.
+pub const DEFAULT_RUNNER_FACTORIES: &[&str] = &["defineRunner"];
+
+/// The runner a schema belongs to: the runner whose factory call takes it as
+/// its input schema, otherwise the first runner whose name is contained in
+/// the schema name, e.g. `fetchUserInputSchema` → `fetchUser`.
+pub fn schema_runner_name<'a>(schema_name: &str, runners: &'a [RunnerInfo]) -> Option<&'a str> {
+    runners
+        .iter()
+        .find(|runner| runner.input_schema.as_deref() == Some(schema_name))
+        .or_else(|| runners.iter().find(|runner| schema_name.contains(&runner.name)))
+        .map(|runner| runner.name.as_str())
+}
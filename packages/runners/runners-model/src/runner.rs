@@ -0,0 +1,25 @@
+/// What a parser knows about a function when deciding if it is a runner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunnerCandidate {
+    /// Exported from the module under a name (declaration or `const` binding)
+    pub exported: bool,
+    pub is_async: bool,
+    /// The module prologue contains `"use runner"`
+    pub module_directive: bool,
+    /// The function body prologue contains `"use runner"`
+    pub function_directive: bool,
+}
+
+impl RunnerCandidate {
+    /// Runners are exported async functions covered by a `"use runner"`
+    /// directive, either in their own body or at the top of the module.
+    pub fn is_runner(&self) -> bool {
+        self.exported && self.is_async && (self.module_directive || self.function_directive)
+    }
+
+    /// A function-level directive on a function that can't run as a runner
+    /// because it is not async.
+    pub fn is_non_async_runner(&self) -> bool {
+        self.function_directive && !self.is_async
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// Runners and schemas found in one file, as written to `runner-schemas.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaMetadata {
+    pub file: String,
+    pub runners: Vec<RunnerInfo>,
+    pub schemas: Vec<SchemaInfo>,
+    /// Set when the file had syntax errors, so runners may be missing
+    #[serde(default)]
+    pub partial: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunnerInfo {
+    pub name: String,
+    pub line: usize,
+    /// Schema passed to a runner factory, e.g. `inputSchema` in
+    /// `defineRunner(inputSchema, async (ctx, input) => {})`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_schema: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaInfo {
+    pub name: String,
+    pub runner_name: Option<String>,
+    pub line: usize,
+}
@@ -0,0 +1,64 @@
+use runners_model::{
+    damerau_levenshtein, is_directive_typo, normalize_directive, DEFAULT_TYPO_THRESHOLD,
+};
+
+#[test]
+fn distance_counts_single_edits() {
+    assert_eq!(damerau_levenshtein("use runner", "use runner"), 0);
+    assert_eq!(damerau_levenshtein("use rnner", "use runner"), 1);
+    assert_eq!(damerau_levenshtein("use runnerr", "use runner"), 1);
+    assert_eq!(damerau_levenshtein("use rumner", "use runner"), 1);
+    assert_eq!(damerau_levenshtein("", "abc"), 3);
+    assert_eq!(damerau_levenshtein("abc", ""), 3);
+}
+
+#[test]
+fn adjacent_transpositions_are_one_edit() {
+    assert_eq!(damerau_levenshtein("use rnuner", "use runner"), 1);
+    assert_eq!(damerau_levenshtein("sue runner", "use runner"), 1);
+    assert_eq!(damerau_levenshtein("ab", "ba"), 1);
+    // Optimal string alignment: a transposed pair is not edited again
+    assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+}
+
+#[test]
+fn distance_counts_characters_not_bytes() {
+    assert_eq!(damerau_levenshtein("use rünner", "use runner"), 1);
+}
+
+#[test]
+fn normalization_folds_case_and_separators() {
+    assert_eq!(normalize_directive("Use Runner"), "use runner");
+    assert_eq!(normalize_directive("  use   runner \t"), "use runner");
+    assert_eq!(normalize_directive("use-runner"), "use runner");
+    assert_eq!(normalize_directive("USE__RUNNER"), "use runner");
+    assert_eq!(normalize_directive("use - _ runner"), "use runner");
+}
+
+#[test]
+fn normalized_spellings_are_always_typos() {
+    for value in ["Use Runner", "USE RUNNER", " use runner ", "use-runner", "use_runner"] {
+        assert!(is_directive_typo(value, 0), "{value:?} should be a typo");
+    }
+}
+
+#[test]
+fn threshold_is_inclusive() {
+    // "use rnuner" is one transposition, "usr rnner" two edits, "usr rnne" three
+    assert!(is_directive_typo("use rnuner", 1));
+    assert!(!is_directive_typo("use rnuner", 0));
+    assert!(is_directive_typo("use rnuner", DEFAULT_TYPO_THRESHOLD));
+    assert!(is_directive_typo("usr rnner", 2));
+    assert!(!is_directive_typo("usr rnner", DEFAULT_TYPO_THRESHOLD));
+    assert!(!is_directive_typo("usr rnne", 2));
+    assert!(is_directive_typo("usr rnne", 3));
+}
+
+#[test]
+fn valid_and_known_directives_are_not_typos() {
+    assert!(!is_directive_typo("use runner", 10));
+    assert!(!is_directive_typo("use strict", 10));
+    assert!(!is_directive_typo("use server", 10));
+    // Closer to "use server" than to "use runner"
+    assert!(!is_directive_typo("use sever", 10));
+}
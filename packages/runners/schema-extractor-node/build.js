@@ -0,0 +1,60 @@
+import { execSync } from "node:child_process";
+import { copyFileSync, existsSync } from "node:fs";
+import { dirname, join } from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = dirname(__filename);
+
+function commandExists(command) {
+  try {
+    execSync(`${command} --version`, { stdio: "ignore", shell: true });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+function libraryFileName() {
+  switch (process.platform) {
+    case "win32":
+      return "schema_extractor_node.dll";
+    case "darwin":
+      return "libschema_extractor_node.dylib";
+    default:
+      return "libschema_extractor_node.so";
+  }
+}
+
+console.log("Building schema-extractor Node binding...");
+
+if (!commandExists("cargo")) {
+  console.error("Rust is required but not installed.");
+  console.error(
+    "Please visit https://rustup.rs and follow the installation instructions."
+  );
+  process.exit(1);
+}
+
+try {
+  execSync("cargo build --release", {
+    cwd: __dirname,
+    stdio: "inherit",
+    shell: true,
+  });
+
+  const librarySource = join(__dirname, "target/release", libraryFileName());
+  if (!existsSync(librarySource)) {
+    console.error(`Native library not found at ${librarySource}`);
+    process.exit(1);
+  }
+
+  // Node loads native addons by the .node extension
+  const addonDest = join(__dirname, "schema-extractor.node");
+  copyFileSync(librarySource, addonDest);
+
+  console.log("Build complete!");
+} catch (error) {
+  console.error("Build failed:", error.message);
+  process.exit(1);
+}
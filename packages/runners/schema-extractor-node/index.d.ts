@@ -0,0 +1,69 @@
+export interface ExtractOptions {
+  /** Project root; patterns are resolved against it and emitted paths are relative to it */
+  cwd?: string;
+  /** Calls whose last argument is a runner function (default `["defineRunner"]`) */
+  runnerFactories?: string[];
+  /** Maximum edit distance at which a directive is reported as a misspelled `"use runner"` (default `1`) */
+  typoThreshold?: number;
+}
+
+export interface RunnerInfo {
+  name: string;
+  line: number;
+  /** Schema passed to a runner factory such as `defineRunner(schema, fn)` */
+  input_schema?: string;
+}
+
+export interface SchemaInfo {
+  name: string;
+  runner_name: string | null;
+  line: number;
+}
+
+export interface SchemaMetadata {
+  /** Path relative to `Extraction.root`, with forward slashes */
+  file: string;
+  runners: RunnerInfo[];
+  schemas: SchemaInfo[];
+  /** True when the file had syntax errors and runners may be missing */
+  partial: boolean;
+}
+
+export interface Position {
+  line: number;
+  column: number;
+}
+
+export interface Diagnostic {
+  file: string;
+  range: { start: Position; end: Position } | null;
+  severity: "error" | "warning";
+  code:
+    | "unreadable-file"
+    | "parse-error"
+    | "unmatched-schema"
+    | "duplicate-runner"
+    | "misspelled-directive";
+  message: string;
+}
+
+/** Same envelope the CLI writes to `runner-schemas.json` */
+export interface Extraction {
+  version: number;
+  generator: { name: string; version: string };
+  root: string;
+  files: SchemaMetadata[];
+  diagnostics: Diagnostic[];
+}
+
+/**
+ * Extract runner and schema metadata in-process.
+ *
+ * Throws if the root or a pattern is invalid; per-file problems are returned
+ * in `diagnostics`.
+ */
+export function extract(
+  patterns?: string[] | null,
+  options?: ExtractOptions | null,
+  onFile?: ((file: SchemaMetadata) => void) | null
+): Extraction;
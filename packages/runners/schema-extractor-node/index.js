@@ -0,0 +1,17 @@
+import { createRequire } from "node:module";
+
+const require = createRequire(import.meta.url);
+const binding = require("./schema-extractor.node");
+
+/**
+ * Extract runner and schema metadata in-process.
+ *
+ * @param {string[] | null | undefined} patterns Glob patterns, defaults to the CLI defaults
+ * @param {import("./index.d.ts").ExtractOptions | null | undefined} options
+ * @param {((file: import("./index.d.ts").SchemaMetadata) => void) | null | undefined} onFile
+ *   Called with each file's metadata as soon as it has been extracted
+ * @returns {import("./index.d.ts").Extraction}
+ */
+export function extract(patterns, options, onFile) {
+  return binding.extract(patterns ?? null, options ?? null, onFile ?? null);
+}
@@ -0,0 +1,77 @@
+//! Node.js binding for `schema_extractor`.
+//!
+//! Runs extraction in-process, so build tooling gets the metadata object
+//! directly instead of spawning the `schema-extractor` binary and reading a
+//! temporary JSON file.
+
+use napi::{Env, JsFunction, JsUnknown};
+use napi_derive::napi;
+use schema_extractor::{extract_project_with, ExtractOptions as CoreExtractOptions};
+use std::path::PathBuf;
+
+#[napi(object)]
+pub struct ExtractOptions {
+    /// Project root; patterns are resolved against it and emitted paths are relative to it
+    pub cwd: Option<String>,
+    /// Calls whose last argument is a runner function, e.g. `defineRunner`
+    pub runner_factories: Option<Vec<String>>,
+    /// Maximum edit distance at which a directive is reported as a misspelled `"use runner"`
+    pub typo_threshold: Option<u32>,
+}
+
+/// Extract runner and schema metadata.
+///
+/// Returns the same envelope the CLI writes to `runner-schemas.json`. When
+/// `onFile` is given it is called with each file's metadata as soon as it has
+/// been extracted.
+#[napi(
+    ts_args_type = "patterns?: string[] | null, options?: ExtractOptions | null, onFile?: ((file: SchemaMetadata) => void) | null",
+    ts_return_type = "Extraction"
+)]
+pub fn extract(
+    env: Env,
+    patterns: Option<Vec<String>>,
+    options: Option<ExtractOptions>,
+    on_file: Option<JsFunction>,
+) -> napi::Result<JsUnknown> {
+    let mut core_options = CoreExtractOptions::default();
+    if let Some(patterns) = patterns {
+        core_options.patterns = patterns;
+    }
+    if let Some(options) = options {
+        if let Some(cwd) = options.cwd {
+            core_options.root = PathBuf::from(cwd);
+        }
+        if let Some(runner_factories) = options.runner_factories {
+            core_options.runner_factories = runner_factories;
+        }
+        if let Some(typo_threshold) = options.typo_threshold {
+            core_options.typo_threshold = typo_threshold as usize;
+        }
+    }
+
+    // Errors from the callback are kept and rethrown once extraction stops,
+    // since the core walk cannot be aborted from inside `on_file`
+    let mut callback_error: Option<napi::Error> = None;
+    let extraction = extract_project_with(&core_options, |metadata| {
+        let Some(on_file) = &on_file else {
+            return;
+        };
+        if callback_error.is_some() {
+            return;
+        }
+        let result = env
+            .to_js_value(metadata)
+            .and_then(|value| on_file.call(None, &[value]));
+        if let Err(e) = result {
+            callback_error = Some(e);
+        }
+    })
+    .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+
+    if let Some(e) = callback_error {
+        return Err(e);
+    }
+
+    env.to_js_value(&extraction)
+}
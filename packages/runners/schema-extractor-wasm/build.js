@@ -0,0 +1,87 @@
+import { execSync } from "node:child_process";
+import { copyFileSync, existsSync } from "node:fs";
+import { dirname, join } from "node:path";
+import { fileURLToPath } from "node:url";
+
+const __filename = fileURLToPath(import.meta.url);
+const __dirname = dirname(__filename);
+
+function runCommand(command, env = process.env) {
+  try {
+    execSync(command, { cwd: __dirname, stdio: "inherit", shell: true, env });
+  } catch (error) {
+    console.error(`Command failed: ${command}: ${error}`);
+    process.exit(1);
+  }
+}
+
+function commandExists(command) {
+  try {
+    execSync(`${command} --version`, { stdio: "ignore", shell: true });
+    return true;
+  } catch {
+    return false;
+  }
+}
+
+console.log("Building schema-extractor WASM...");
+
+if (!commandExists("cargo")) {
+  console.error("Rust is required but not installed.");
+  console.error(
+    "Please visit https://rustup.rs and follow the installation instructions."
+  );
+  console.error('After installing, run "rustup target add wasm32-wasip1"');
+  process.exit(1);
+}
+
+// tree-sitter is written in C, so the grammar needs a WASI-capable clang
+const wasiSdkPath = process.env.WASI_SDK_PATH;
+if (!(wasiSdkPath && existsSync(join(wasiSdkPath, "bin/clang")))) {
+  console.error("WASI_SDK_PATH must point to a wasi-sdk installation.");
+  console.error(
+    "Download it from https://github.com/WebAssembly/wasi-sdk/releases"
+  );
+  process.exit(1);
+}
+
+console.log("Checking wasm32-wasip1 target...");
+try {
+  const installedTargets = execSync("rustup target list --installed", {
+    stdio: "pipe",
+    shell: true,
+  }).toString();
+  if (installedTargets.includes("wasm32-wasip1")) {
+    console.log("wasm32-wasip1 target already installed");
+  } else {
+    console.log("wasm32-wasip1 target not found, installing...");
+    runCommand("rustup target add wasm32-wasip1");
+  }
+} catch (error) {
+  console.error("Failed to check/install wasm32-wasip1 target:", error.message);
+  process.exit(1);
+}
+
+console.log("Running cargo build...");
+runCommand("cargo build-wasm", {
+  ...process.env,
+  CC_wasm32_wasip1: join(wasiSdkPath, "bin/clang"),
+  AR_wasm32_wasip1: join(wasiSdkPath, "bin/llvm-ar"),
+  CFLAGS_wasm32_wasip1: `--sysroot=${join(wasiSdkPath, "share/wasi-sysroot")}`,
+});
+
+const wasmSource = join(
+  __dirname,
+  "target/wasm32-wasip1/release/schema_extractor_wasm.wasm"
+);
+const wasmDest = join(__dirname, "schema_extractor.wasm");
+
+if (!existsSync(wasmSource)) {
+  console.error(`WASM file not found at ${wasmSource}`);
+  process.exit(1);
+}
+
+console.log(`Copying WASM file from ${wasmSource} to ${wasmDest}...`);
+copyFileSync(wasmSource, wasmDest);
+
+console.log("Build complete!");
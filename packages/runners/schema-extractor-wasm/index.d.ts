@@ -0,0 +1,70 @@
+export interface SourceFile {
+  /** Path used to pick the grammar (`.tsx`/`.jsx` vs TypeScript) and recorded as `file` */
+  path: string;
+  content: string;
+}
+
+export interface RunnerInfo {
+  name: string;
+  line: number;
+  /** Schema passed to a runner factory such as `defineRunner(schema, fn)` */
+  input_schema?: string;
+}
+
+export interface SchemaInfo {
+  name: string;
+  runner_name: string | null;
+  line: number;
+}
+
+export interface SchemaMetadata {
+  file: string;
+  runners: RunnerInfo[];
+  schemas: SchemaInfo[];
+  /** True when the file had syntax errors and runners may be missing */
+  partial: boolean;
+}
+
+export interface Position {
+  line: number;
+  column: number;
+}
+
+export interface Diagnostic {
+  file: string;
+  range: { start: Position; end: Position } | null;
+  severity: "error" | "warning";
+  code:
+    | "unreadable-file"
+    | "parse-error"
+    | "unmatched-schema"
+    | "duplicate-runner"
+    | "misspelled-directive";
+  message: string;
+}
+
+/** `files` and `diagnostics` of the `runner-schemas.json` envelope */
+export interface ExtractResult {
+  files: SchemaMetadata[];
+  diagnostics: Diagnostic[];
+}
+
+export interface ExtractOptions {
+  /** Calls whose last argument is a runner function (default `["defineRunner"]`) */
+  runnerFactories?: string[];
+  /** Maximum edit distance at which a directive is reported as a misspelled `"use runner"` (default `1`) */
+  typoThreshold?: number;
+}
+
+export interface Extractor {
+  /**
+   * Extract metadata from in-memory files. Files without a `"use runner"`
+   * directive are skipped.
+   */
+  extract(files: SourceFile[], options?: ExtractOptions): ExtractResult;
+}
+
+/** Instantiate the extractor from the contents of `schema_extractor.wasm` */
+export function createExtractor(
+  source: BufferSource | WebAssembly.Module
+): Promise<Extractor>;
@@ -0,0 +1,121 @@
+const ERRNO_SUCCESS = 0;
+const ERRNO_BADF = 8;
+const ERRNO_NOSYS = 52;
+
+const encoder = new TextEncoder();
+const decoder = new TextDecoder();
+
+/**
+ * Minimal WASI preview1 imports.
+ *
+ * The extractor never reads files or the environment, so only what the Rust
+ * standard library touches at runtime is implemented: randomness for hash
+ * maps, stderr for panics, and an empty environment. Everything else reports
+ * ENOSYS, which keeps the module usable where `node:wasi` is unavailable
+ * (edge runtimes, browsers).
+ */
+function createWasiImports(getMemory) {
+  const view = () => new DataView(getMemory().buffer);
+  const bytes = () => new Uint8Array(getMemory().buffer);
+
+  const implemented = {
+    random_get(ptr, len) {
+      crypto.getRandomValues(bytes().subarray(ptr, ptr + len));
+      return ERRNO_SUCCESS;
+    },
+    environ_sizes_get(countPtr, sizePtr) {
+      view().setUint32(countPtr, 0, true);
+      view().setUint32(sizePtr, 0, true);
+      return ERRNO_SUCCESS;
+    },
+    environ_get() {
+      return ERRNO_SUCCESS;
+    },
+    args_sizes_get(countPtr, sizePtr) {
+      view().setUint32(countPtr, 0, true);
+      view().setUint32(sizePtr, 0, true);
+      return ERRNO_SUCCESS;
+    },
+    args_get() {
+      return ERRNO_SUCCESS;
+    },
+    fd_write(fd, iovsPtr, iovsLen, writtenPtr) {
+      if (fd !== 1 && fd !== 2) {
+        return ERRNO_BADF;
+      }
+      let written = 0;
+      let text = "";
+      for (let i = 0; i < iovsLen; i++) {
+        const ptr = view().getUint32(iovsPtr + i * 8, true);
+        const len = view().getUint32(iovsPtr + i * 8 + 4, true);
+        text += decoder.decode(bytes().subarray(ptr, ptr + len));
+        written += len;
+      }
+      console.error(text.replace(/\n$/, ""));
+      view().setUint32(writtenPtr, written, true);
+      return ERRNO_SUCCESS;
+    },
+    fd_prestat_get() {
+      return ERRNO_BADF;
+    },
+    proc_exit(code) {
+      throw new Error(`schema-extractor wasm exited with code ${code}`);
+    },
+  };
+
+  return new Proxy(implemented, {
+    get(target, name) {
+      return name in target ? target[name] : () => ERRNO_NOSYS;
+    },
+  });
+}
+
+/**
+ * Instantiate the extractor from compiled wasm bytes or a `WebAssembly.Module`.
+ *
+ * @param {BufferSource | WebAssembly.Module} source Contents of `schema_extractor.wasm`
+ * @returns {Promise<import("./index.d.ts").Extractor>}
+ */
+export async function createExtractor(source) {
+  let memory;
+  const imports = {
+    wasi_snapshot_preview1: createWasiImports(() => memory),
+  };
+
+  const instance =
+    source instanceof WebAssembly.Module
+      ? await WebAssembly.instantiate(source, imports)
+      : (await WebAssembly.instantiate(source, imports)).instance;
+  const exports = instance.exports;
+  memory = exports.memory;
+  exports._initialize?.();
+
+  function extract(files, options) {
+    const input = encoder.encode(
+      JSON.stringify({
+        files,
+        runnerFactories: options?.runnerFactories,
+        typoThreshold: options?.typoThreshold,
+      })
+    );
+    const inputPtr = exports.schema_extractor_alloc(input.length) >>> 0;
+    new Uint8Array(memory.buffer, inputPtr, input.length).set(input);
+
+    // Output location is packed as (ptr << 32) | len
+    const packed = exports.schema_extractor_extract(inputPtr, input.length);
+    const outputPtr = Number(packed >> 32n);
+    const outputLen = Number(packed & 0xffffffffn);
+    const output = decoder.decode(
+      new Uint8Array(memory.buffer, outputPtr, outputLen)
+    );
+    exports.schema_extractor_free(outputPtr, outputLen);
+
+    const result = JSON.parse(output);
+    if ("error" in result) {
+      throw new Error(result.error);
+    }
+    return result;
+  }
+
+  return { extract };
+}
@@ -0,0 +1,137 @@
+//! WebAssembly build of the `schema_extractor` core.
+//!
+//! For environments where the native binary or Node addon cannot be
+//! installed. The module never touches the filesystem: the host passes file
+//! contents in and receives metadata back, both as JSON in linear memory.
+//!
+//! Calling convention (see `index.js`):
+//! 1. `schema_extractor_alloc(len)` reserves space for the UTF-8 JSON input
+//! 2. `schema_extractor_extract(ptr, len)` consumes the input and returns the
+//!    output location packed as `(ptr << 32) | len`
+//! 3. `schema_extractor_free(ptr, len)` releases the output once it is read
+
+use schema_extractor::diagnostics::check_duplicate_runners;
+use schema_extractor::{
+    extract_file_with_diagnostics, has_use_runner_directive, Diagnostic, SchemaMetadata,
+    DEFAULT_RUNNER_FACTORIES, DEFAULT_TYPO_THRESHOLD,
+};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A file handed in by the host.
+#[derive(Debug, Deserialize)]
+pub struct SourceFile {
+    /// Path used for the grammar choice and recorded as the metadata's `file`
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExtractInput {
+    pub files: Vec<SourceFile>,
+    /// Calls whose last argument is a runner function, e.g. `defineRunner`
+    #[serde(rename = "runnerFactories", default = "default_runner_factories")]
+    pub runner_factories: Vec<String>,
+    /// Maximum edit distance at which a directive is reported as misspelled
+    #[serde(rename = "typoThreshold", default = "default_typo_threshold")]
+    pub typo_threshold: usize,
+}
+
+fn default_typo_threshold() -> usize {
+    DEFAULT_TYPO_THRESHOLD
+}
+
+fn default_runner_factories() -> Vec<String> {
+    DEFAULT_RUNNER_FACTORIES.iter().map(|f| f.to_string()).collect()
+}
+
+/// Metadata for the given files, in the shape of the `runner-schemas.json` envelope's
+/// `files` and `diagnostics`.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ExtractOutput {
+    Ok {
+        files: Vec<SchemaMetadata>,
+        diagnostics: Vec<Diagnostic>,
+    },
+    Err {
+        error: String,
+    },
+}
+
+/// Extract metadata from in-memory files.
+///
+/// Files without a `"use runner"` directive are skipped, matching the CLI.
+pub fn extract_sources(input: &ExtractInput) -> ExtractOutput {
+    let mut files = Vec::new();
+    let mut diagnostics = Vec::new();
+
+    for source in &input.files {
+        if !has_use_runner_directive(&source.content, Path::new(&source.path)) {
+            continue;
+        }
+        files.push(extract_file_with_diagnostics(
+            &source.content,
+            Path::new(&source.path),
+            &input.runner_factories,
+            input.typo_threshold,
+            &mut diagnostics,
+        ));
+    }
+
+    check_duplicate_runners(&files, &mut diagnostics);
+
+    ExtractOutput::Ok { files, diagnostics }
+}
+
+fn extract_json(input: &[u8]) -> String {
+    let output = match serde_json::from_slice::<ExtractInput>(input) {
+        Ok(input) => extract_sources(&input),
+        Err(e) => ExtractOutput::Err {
+            error: format!("Invalid input: {}", e),
+        },
+    };
+    serde_json::to_string(&output).unwrap_or_else(|e| {
+        format!(
+            "{{\"error\":{}}}",
+            serde_json::Value::String(format!("Failed to serialize metadata: {}", e))
+        )
+    })
+}
+
+/// Allocate `len` bytes of linear memory for the host to write input into.
+#[no_mangle]
+pub extern "C" fn schema_extractor_alloc(len: usize) -> *mut u8 {
+    let mut buffer = Vec::<u8>::with_capacity(len);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
+    ptr
+}
+
+/// Release memory returned by [`schema_extractor_alloc`] or [`schema_extractor_extract`].
+///
+/// # Safety
+///
+/// `ptr` and `len` must describe a buffer previously handed out by this module
+/// that has not been freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn schema_extractor_free(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+/// Run extraction over the JSON input at `ptr` and return the packed output location.
+///
+/// The input buffer is freed. The output is always JSON: either
+/// `{ files, diagnostics }` or `{ error }`.
+///
+/// # Safety
+///
+/// `ptr` must come from [`schema_extractor_alloc`] with `len` bytes written.
+#[no_mangle]
+pub unsafe extern "C" fn schema_extractor_extract(ptr: *mut u8, len: usize) -> u64 {
+    let input = Vec::from_raw_parts(ptr, len, len);
+    let output = extract_json(&input).into_bytes().into_boxed_slice();
+    let out_len = output.len();
+    let out_ptr = Box::into_raw(output) as *mut u8;
+    ((out_ptr as u64) << 32) | out_len as u64
+}
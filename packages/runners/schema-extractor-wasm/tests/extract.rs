@@ -0,0 +1,58 @@
+use schema_extractor::{DEFAULT_RUNNER_FACTORIES, DEFAULT_TYPO_THRESHOLD};
+use schema_extractor_wasm::{extract_sources, ExtractInput, ExtractOutput, SourceFile};
+
+fn source(path: &str, content: &str) -> SourceFile {
+    SourceFile {
+        path: path.to_string(),
+        content: content.to_string(),
+    }
+}
+
+#[test]
+fn extracts_runner_files_and_skips_others() {
+    let output = extract_sources(&ExtractInput {
+        files: vec![
+            source("runners/a.ts", "\"use runner\";\nexport async function a() {}"),
+            source("lib/b.ts", "export async function b() {}"),
+        ],
+        runner_factories: DEFAULT_RUNNER_FACTORIES.iter().map(|f| f.to_string()).collect(),
+        typo_threshold: DEFAULT_TYPO_THRESHOLD,
+    });
+
+    let ExtractOutput::Ok { files, diagnostics } = output else {
+        panic!("extraction should succeed");
+    };
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].file, "runners/a.ts");
+    assert_eq!(files[0].runners[0].name, "a");
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn reports_duplicate_runners_across_files() {
+    let output = extract_sources(&ExtractInput {
+        files: vec![
+            source("runners/a.ts", "\"use runner\";\nexport async function run() {}"),
+            source("runners/b.tsx", "\"use runner\";\nexport async function run() {}"),
+        ],
+        runner_factories: DEFAULT_RUNNER_FACTORIES.iter().map(|f| f.to_string()).collect(),
+        typo_threshold: DEFAULT_TYPO_THRESHOLD,
+    });
+
+    let ExtractOutput::Ok { diagnostics, .. } = output else {
+        panic!("extraction should succeed");
+    };
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].file, "runners/b.tsx");
+}
+
+#[test]
+fn typo_threshold_defaults_and_can_be_set() {
+    let input = r#"{"files":[]}"#;
+    let input: ExtractInput = serde_json::from_str(input).unwrap();
+    assert_eq!(input.typo_threshold, DEFAULT_TYPO_THRESHOLD);
+
+    let input = r#"{"files":[],"typoThreshold":1}"#;
+    let input: ExtractInput = serde_json::from_str(input).unwrap();
+    assert_eq!(input.typo_threshold, 1);
+}
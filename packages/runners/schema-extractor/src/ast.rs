@@ -1,51 +1,68 @@
-use crate::types::{RunnerInfo, SchemaInfo};
-use tree_sitter::{Language, Parser as TSParser, Node};
+use crate::types::{Position, Range, RunnerInfo, SchemaInfo};
+use runners_model::{
+    is_directive_typo, is_schema_name, is_use_runner_directive, schema_runner_name,
+    RunnerCandidate,
+};
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser as TSParser, Point, Tree};
 
-/// Get the TypeScript language from tree-sitter-typescript
-fn get_typescript_language() -> Language {
-    tree_sitter_typescript::language_typescript()
+/// A syntax error recovered from by tree-sitter.
+#[derive(Debug)]
+pub struct SyntaxError {
+    pub range: Range,
+    pub message: String,
 }
 
-/// Extract line number from a tree-sitter node
-fn get_line_number(node: &Node, source: &str) -> usize {
-    let start_byte = node.start_byte();
-    source[..start_byte].matches('\n').count() + 1
+/// A directive prologue string that looks like a misspelled `"use runner"`.
+#[derive(Debug)]
+pub struct MisspelledDirective {
+    pub range: Range,
+    pub directive: String,
 }
 
-/// Check if a node is exported (has export modifier)
-fn is_exported(node: &Node) -> bool {
-    // Check if the node itself is an export statement
-    if node.kind() == "export_statement" {
-        return true;
-    }
-    
-    // Check parent nodes for export_statement
-    let mut current = node.parent();
-    while let Some(n) = current {
-        if n.kind() == "export_statement" {
-            return true;
-        }
-        current = n.parent();
-    }
-    
-    // Check for export modifier as a sibling or in the declaration
-    // For function_declaration, check if first child is "export"
-    if let Some(first_child) = node.child(0) {
-        if first_child.kind() == "export" {
-            return true;
+/// Grammar used to parse a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// `.ts`, `.mts` and `.cts`, and files with any other extension
+    TypeScript,
+    /// `.tsx` and JavaScript (`.js`, `.jsx`, `.mjs`, `.cjs`), where `<` starts
+    /// a JSX element instead of a type assertion
+    Tsx,
+}
+
+impl Dialect {
+    /// Pick the grammar from the file extension. JavaScript files may contain
+    /// JSX and never contain `<Type>` assertions, so they use the TSX grammar;
+    /// anything else is parsed as TypeScript.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("tsx") | Some("jsx") | Some("js") | Some("mjs") | Some("cjs") => Dialect::Tsx,
+            _ => Dialect::TypeScript,
         }
     }
-    
-    // Check parent's first child for export (common pattern)
-    if let Some(parent) = node.parent() {
-        if let Some(first_child) = parent.child(0) {
-            if first_child.kind() == "export" {
-                return true;
-            }
-        }
+}
+
+/// Get the tree-sitter-typescript language for the dialect
+fn get_typescript_language(dialect: Dialect) -> Language {
+    match dialect {
+        Dialect::TypeScript => tree_sitter_typescript::language_typescript(),
+        Dialect::Tsx => tree_sitter_typescript::language_tsx(),
     }
-    
-    false
+}
+
+/// Parse `content` with the grammar for `dialect`. Every pass over a file
+/// works on this one tree. `None` only if tree-sitter gives up on the file.
+pub fn parse(content: &str, dialect: Dialect) -> Option<Tree> {
+    let language = get_typescript_language(dialect);
+    let mut parser = TSParser::new();
+    parser.set_language(&language).expect("Failed to set TypeScript language");
+    parser.parse(content, None)
+}
+
+/// Extract line number from a tree-sitter node
+fn get_line_number(node: &Node, source: &str) -> usize {
+    let start_byte = node.start_byte();
+    source[..start_byte].matches('\n').count() + 1
 }
 
 /// Extract function name from a function declaration or arrow function
@@ -88,150 +105,678 @@ fn is_async_function(node: &Node) -> bool {
     false
 }
 
-/// Finds exported async runner functions in TypeScript code using tree-sitter AST parsing.
-pub fn find_exported_runners(content: &str) -> Vec<RunnerInfo> {
-    let mut runners = Vec::new();
-    
-    let language = get_typescript_language();
-    let mut parser = TSParser::new();
-    parser.set_language(&language).expect("Failed to set TypeScript language");
-    
-    let tree = match parser.parse(content, None) {
-        Some(tree) => tree,
-        None => return runners,
-    };
-    
-    let root_node = tree.root_node();
-    
-    // Recursively walk the AST to find exported async functions
-    fn walk_node<'a>(node: Node<'a>, content: &str, runners: &mut Vec<RunnerInfo>) {
-        match node.kind() {
-            "function_declaration" => {
-                if is_exported(&node) && is_async_function(&node) {
-                    if let Some(name) = extract_function_name(&node, content) {
-                        let line = get_line_number(&node, content);
-                        runners.push(RunnerInfo { name, line });
-                    }
-                }
+/// Leading string statements of a `program` or `statement_block`, with their values.
+fn prologue_directives<'a>(block: &Node<'a>, source: &str) -> Vec<(Node<'a>, String)> {
+    let mut directives = Vec::new();
+    let mut cursor = block.walk();
+    for statement in block.named_children(&mut cursor) {
+        match statement.kind() {
+            "comment" | "hash_bang_line" => continue,
+            "expression_statement" => {
+                let Some(string) = statement.named_child(0).filter(|n| n.kind() == "string")
+                else {
+                    break;
+                };
+                let text = &source[string.start_byte()..string.end_byte()];
+                // Strip the surrounding quotes; directives compare by cooked value
+                let value = cook_string(&text[1..text.len() - 1]);
+                directives.push((statement, value));
             }
-            "lexical_declaration" | "variable_declaration" => {
-                if is_exported(&node) {
-                    // Check if this is a const/let declaration with async arrow function
-                    for i in 0..node.child_count() {
-                        if let Some(child) = node.child(i) {
-                            if child.kind() == "variable_declarator" {
-                                // Check if the value is an async arrow function
-                                for j in 0..child.child_count() {
-                                    if let Some(value_node) = child.child(j) {
-                                        if value_node.kind() == "arrow_function" && is_async_function(&value_node) {
-                                            // Extract name from the variable_declarator
-                                            if let Some(name_node) = child.child(0) {
-                                                if name_node.kind() == "identifier" || name_node.kind() == "property_identifier" {
-                                                    let name = &content[name_node.start_byte()..name_node.end_byte()];
-                                                    let line = get_line_number(&node, content);
-                                                    runners.push(RunnerInfo { 
-                                                        name: name.to_string(), 
-                                                        line 
-                                                    });
-                                                }
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+            _ => break,
+        }
+    }
+    directives
+}
+
+/// Resolve the escape sequences in the body of a string literal, giving the
+/// value the string evaluates to (`"use\x20runner"` is `use runner`).
+fn cook_string(raw: &str) -> String {
+    let mut cooked = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            cooked.push(c);
+            continue;
+        }
+        let Some(escape) = chars.next() else {
+            break;
+        };
+        match escape {
+            'n' => cooked.push('\n'),
+            't' => cooked.push('\t'),
+            'r' => cooked.push('\r'),
+            'b' => cooked.push('\u{8}'),
+            'f' => cooked.push('\u{c}'),
+            'v' => cooked.push('\u{b}'),
+            '0' => cooked.push('\0'),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                push_code_point(&mut cooked, &hex);
             }
-            _ => {}
+            'u' if chars.peek() == Some(&'{') => {
+                chars.next();
+                let hex: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                push_code_point(&mut cooked, &hex);
+            }
+            'u' => {
+                let hex: String = chars.by_ref().take(4).collect();
+                push_code_point(&mut cooked, &hex);
+            }
+            // Line continuation
+            '\n' | '\u{2028}' | '\u{2029}' => {}
+            '\r' => {
+                chars.next_if_eq(&'\n');
+            }
+            other => cooked.push(other),
         }
-        
-        // Recursively visit children
+    }
+
+    cooked
+}
+
+fn push_code_point(cooked: &mut String, hex: &str) {
+    // Lone surrogates and malformed escapes can't be a directive we care about
+    let c = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32);
+    cooked.push(c.unwrap_or(char::REPLACEMENT_CHARACTER));
+}
+
+/// Whether a function's body starts with a `"use runner"` directive.
+fn has_function_directive(function: &Node, source: &str) -> bool {
+    function
+        .child_by_field_name("body")
+        .filter(|body| body.kind() == "statement_block")
+        .is_some_and(|body| {
+            prologue_directives(&body, source)
+                .iter()
+                .any(|(_, value)| is_use_runner_directive(value))
+        })
+}
+
+/// Whether the module prologue or any function body prologue contains a
+/// `"use runner"` directive. Mentions in comments, template literals or
+/// ordinary strings don't count.
+pub fn has_runner_directive(root_node: &Node, content: &str) -> bool {
+    fn walk_node(node: Node, content: &str) -> bool {
+        if is_function_like(&node) && has_function_directive(&node, content) {
+            return true;
+        }
+
+        let mut cursor = node.walk();
+        let found = node.children(&mut cursor).any(|child| walk_node(child, content));
+        found
+    }
+
+    has_module_directive(root_node, content) || walk_node(*root_node, content)
+}
+
+/// Whether the module starts with a `"use runner"` directive.
+fn has_module_directive(root: &Node, source: &str) -> bool {
+    prologue_directives(root, source)
+        .iter()
+        .any(|(_, value)| is_use_runner_directive(value))
+}
+
+fn is_function_like(node: &Node) -> bool {
+    matches!(
+        node.kind(),
+        "function_declaration"
+            | "function_expression"
+            | "function"
+            | "generator_function_declaration"
+            | "generator_function"
+            | "arrow_function"
+            | "method_definition"
+    )
+}
+
+/// Convert a tree-sitter point (0-based row, byte column) to a 1-based line/column
+/// position counted in characters.
+fn to_position(point: Point, byte: usize, source: &str) -> Position {
+    let line_start = byte - point.column;
+    Position {
+        line: point.row + 1,
+        column: source[line_start..byte].chars().count() + 1,
+    }
+}
+
+fn node_range(node: &Node, source: &str) -> Range {
+    Range {
+        start: to_position(node.start_position(), node.start_byte(), source),
+        end: to_position(node.end_position(), node.end_byte(), source),
+    }
+}
+
+/// Finds `ERROR` and `MISSING` nodes reported by tree-sitter.
+///
+/// tree-sitter recovers from syntax errors and still returns a tree, so
+/// declarations around an error can silently drop out of the extracted
+/// metadata. Every recovery point is returned with its location.
+pub fn find_syntax_errors(root_node: &Node, content: &str) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+
+    fn walk_node(node: Node, content: &str, errors: &mut Vec<SyntaxError>) {
+        if node.is_error() {
+            errors.push(SyntaxError {
+                range: node_range(&node, content),
+                message: "Syntax error".to_string(),
+            });
+            return;
+        }
+        if node.is_missing() {
+            errors.push(SyntaxError {
+                range: node_range(&node, content),
+                message: format!("Missing \"{}\"", node.kind()),
+            });
+            return;
+        }
+        if !node.has_error() {
+            return;
+        }
+
         let mut cursor = node.walk();
         if cursor.goto_first_child() {
             loop {
-                walk_node(cursor.node(), content, runners);
+                walk_node(cursor.node(), content, errors);
                 if !cursor.goto_next_sibling() {
                     break;
                 }
             }
         }
     }
-    
-    walk_node(root_node, content, &mut runners);
-    
-    runners
+
+    walk_node(*root_node, content, &mut errors);
+
+    errors
 }
 
-/// Finds exported schema variables in TypeScript code using tree-sitter AST parsing.
-pub fn find_exported_schemas(content: &str, runner_names: &[String]) -> Vec<SchemaInfo> {
-    let mut schemas = Vec::new();
-    
-    let language = get_typescript_language();
-    let mut parser = TSParser::new();
-    parser.set_language(&language).expect("Failed to set TypeScript language");
-    
-    let tree = match parser.parse(content, None) {
-        Some(tree) => tree,
-        None => return schemas,
-    };
-    
-    let root_node = tree.root_node();
-    
-    // Recursively walk the AST to find exported schema variables
-    fn walk_node<'a>(node: Node<'a>, content: &str, schemas: &mut Vec<SchemaInfo>, runner_names: &[String]) {
+/// A function bound to a name at module scope, checked against the runner
+/// rules once we know how it is exported.
+struct FunctionBinding {
+    /// Local name, used to resolve `export { name }` lists
+    name: String,
+    /// Name the function is exported under, if it is exported where it is declared
+    export_name: Option<String>,
+    line: usize,
+    is_async: bool,
+    function_directive: bool,
+    /// Schema argument of a runner factory call
+    input_schema: Option<String>,
+}
+
+fn is_default_export(export_statement: &Node) -> bool {
+    let mut cursor = export_statement.walk();
+    let is_default = export_statement
+        .children(&mut cursor)
+        .any(|child| child.kind() == "default");
+    is_default
+}
+
+/// A function-valued initializer, seen through the expressions wrapping it.
+struct FunctionValue<'tree> {
+    function: Node<'tree>,
+    input_schema: Option<String>,
+}
+
+/// Unwraps parenthesized, `satisfies` and `as` expressions and calls to one of
+/// `runner_factories`, such as `defineRunner(inputSchema, async (ctx) => {})`.
+/// A factory's function is its last argument; a leading identifier argument
+/// is taken as the input schema.
+fn unwrap_function_value<'tree>(
+    node: Node<'tree>,
+    source: &str,
+    runner_factories: &[String],
+) -> Option<FunctionValue<'tree>> {
+    match node.kind() {
+        "arrow_function" | "function_expression" => Some(FunctionValue {
+            function: node,
+            input_schema: None,
+        }),
+        "parenthesized_expression" | "satisfies_expression" | "as_expression" => {
+            unwrap_function_value(node.named_child(0)?, source, runner_factories)
+        }
+        "call_expression" => {
+            let callee = node.child_by_field_name("function")?;
+            let callee_name = node_text(&callee, source);
+            if callee.kind() != "identifier"
+                || !runner_factories.iter().any(|factory| factory == callee_name)
+            {
+                return None;
+            }
+
+            let arguments = node.child_by_field_name("arguments")?;
+            let mut cursor = arguments.walk();
+            let arguments: Vec<Node> = arguments
+                .named_children(&mut cursor)
+                .filter(|argument| argument.kind() != "comment")
+                .collect();
+            let (function, rest) = arguments.split_last()?;
+            let mut value = unwrap_function_value(*function, source, runner_factories)?;
+            if value.input_schema.is_none() {
+                value.input_schema = rest
+                    .first()
+                    .filter(|argument| argument.kind() == "identifier")
+                    .map(|argument| node_text(argument, source).to_string());
+            }
+            Some(value)
+        }
+        _ => None,
+    }
+}
+
+fn node_text<'a>(node: &Node, source: &'a str) -> &'a str {
+    &source[node.start_byte()..node.end_byte()]
+}
+
+/// `module.exports`
+fn is_module_exports(member: &Node, source: &str) -> bool {
+    member.kind() == "member_expression"
+        && member
+            .child_by_field_name("object")
+            .is_some_and(|object| object.kind() == "identifier" && node_text(&object, source) == "module")
+        && member
+            .child_by_field_name("property")
+            .is_some_and(|property| node_text(&property, source) == "exports")
+}
+
+/// `exports`
+fn is_exports_identifier(node: &Node, source: &str) -> bool {
+    node.kind() == "identifier" && node_text(node, source) == "exports"
+}
+
+/// Start of the last top-level `module.exports = ...` statement. Exports
+/// assigned to the replaced object before it, and to `exports` anywhere, are
+/// lost once it runs.
+fn last_exports_reassignment(root: &Node, source: &str) -> Option<usize> {
+    let mut cursor = root.walk();
+    let last = root
+        .named_children(&mut cursor)
+        .filter(|statement| {
+            statement
+                .named_child(0)
+                .filter(|assignment| assignment.kind() == "assignment_expression")
+                .and_then(|assignment| assignment.child_by_field_name("left"))
+                .is_some_and(|target| is_module_exports(&target, source))
+        })
+        .last()
+        .map(|statement| statement.start_byte());
+    last
+}
+
+/// Declarations found at module scope.
+#[derive(Default)]
+struct ModuleScope<'a> {
+    runner_factories: &'a [String],
+    /// See [`last_exports_reassignment`]
+    exports_reassigned_at: Option<usize>,
+    functions: Vec<FunctionBinding>,
+    /// `export { local as exported }` lists, as (local, exported) pairs
+    export_lists: Vec<(String, String)>,
+    /// Exported variables with a schema-like name, with their line
+    schemas: Vec<(String, usize)>,
+}
+
+impl<'a> ModuleScope<'a> {
+    /// Collect module-scope declarations: top-level statements, declarations
+    /// wrapped in `export` statements and the bodies of `namespace` blocks,
+    /// whose members are named `Namespace.member`. Function bodies are never
+    /// entered, so helpers declared inside a runner are not runners.
+    fn collect(root: &Node, source: &str, runner_factories: &'a [String]) -> Self {
+        let mut scope = Self {
+            runner_factories,
+            exports_reassigned_at: last_exports_reassignment(root, source),
+            ..Self::default()
+        };
+        scope.collect_block(root, source, "", true);
+        scope
+    }
+
+    /// `scope_exported` is whether exports from this block are reachable
+    /// from the module, i.e. every enclosing namespace is exported.
+    fn collect_block(&mut self, block: &Node, source: &str, prefix: &str, scope_exported: bool) {
+        let mut cursor = block.walk();
+        for statement in block.named_children(&mut cursor) {
+            match statement.kind() {
+                "export_statement" => self.collect_export(&statement, source, prefix, scope_exported),
+                // A non-exported `namespace Foo {}` parses as an expression
+                // statement, as do CommonJS `exports.name = ...` assignments
+                "expression_statement" => match statement.named_child(0) {
+                    Some(namespace) if namespace.kind() == "internal_module" => {
+                        self.collect_namespace(&namespace, source, prefix, false);
+                    }
+                    Some(assignment)
+                        if assignment.kind() == "assignment_expression" && prefix.is_empty() =>
+                    {
+                        self.collect_commonjs_export(&assignment, &statement, source);
+                    }
+                    _ => {}
+                },
+                _ => self.collect_declaration(&statement, source, prefix, false),
+            }
+        }
+    }
+
+    fn collect_export(&mut self, statement: &Node, source: &str, prefix: &str, scope_exported: bool) {
+        let is_default = is_default_export(statement);
+
+        // Includes `export default async function name() {}`, which keeps its name
+        if let Some(declaration) = statement.child_by_field_name("declaration") {
+            self.collect_declaration(&declaration, source, prefix, scope_exported);
+        }
+
+        // `export default async () => {}` or `export default async function () {}`
+        if let Some(value) = statement.child_by_field_name("value").filter(|_| is_default) {
+            if let Some(value) = unwrap_function_value(value, source, self.runner_factories) {
+                self.push_default(value, get_line_number(statement, source), source);
+            }
+        }
+
+        // `export { local as alias }`, but not `export { x } from "./other"`
+        if prefix.is_empty() && statement.child_by_field_name("source").is_none() {
+            let mut cursor = statement.walk();
+            for clause in statement.named_children(&mut cursor) {
+                if clause.kind() != "export_clause" {
+                    continue;
+                }
+                let mut clause_cursor = clause.walk();
+                for specifier in clause.named_children(&mut clause_cursor) {
+                    let Some(local) = specifier.child_by_field_name("name") else {
+                        continue;
+                    };
+                    let local = node_text(&local, source).to_string();
+                    let exported = specifier
+                        .child_by_field_name("alias")
+                        .map(|alias| node_text(&alias, source).to_string())
+                        .unwrap_or_else(|| local.clone());
+                    self.export_lists.push((local, exported));
+                }
+            }
+        }
+    }
+
+    fn push_default(&mut self, value: FunctionValue, line: usize, source: &str) {
+        self.functions.push(FunctionBinding {
+            name: runners_model::DEFAULT_EXPORT_NAME.to_string(),
+            export_name: Some(runners_model::DEFAULT_EXPORT_NAME.to_string()),
+            line,
+            is_async: is_async_function(&value.function),
+            function_directive: has_function_directive(&value.function, source),
+            input_schema: value.input_schema,
+        });
+    }
+
+    fn collect_declaration(&mut self, node: &Node, source: &str, prefix: &str, exported: bool) {
         match node.kind() {
+            "function_declaration" => {
+                if let Some(name) = extract_function_name(node, source) {
+                    let name = format!("{prefix}{name}");
+                    self.functions.push(FunctionBinding {
+                        export_name: exported.then(|| name.clone()),
+                        name,
+                        line: get_line_number(node, source),
+                        is_async: is_async_function(node),
+                        function_directive: has_function_directive(node, source),
+                        input_schema: None,
+                    });
+                }
+            }
             "lexical_declaration" | "variable_declaration" => {
-                if is_exported(&node) {
-                    // Check for variable declarators
-                    for i in 0..node.child_count() {
-                        if let Some(child) = node.child(i) {
-                            if child.kind() == "variable_declarator" {
-                                // Extract variable name
-                                if let Some(name_node) = child.child(0) {
-                                    if name_node.kind() == "identifier" || name_node.kind() == "property_identifier" {
-                                        let name = &content[name_node.start_byte()..name_node.end_byte()];
-                                        
-                                        // Check if name contains "Schema" (case-insensitive)
-                                        if name.to_lowercase().contains("schema") {
-                                            let name = name.to_string();
-                                            
-                                            // Try to match with runner names
-                                            let runner_name = runner_names.iter().find(|runner| {
-                                                name.contains(runner.as_str())
-                                            });
-                                            
-                                            let line = get_line_number(&node, content);
-                                            schemas.push(SchemaInfo {
-                                                name,
-                                                runner_name: runner_name.cloned(),
-                                                line,
-                                            });
-                                        }
-                                    }
-                                }
-                            }
+                let line = get_line_number(node, source);
+                let mut cursor = node.walk();
+                for declarator in node.named_children(&mut cursor) {
+                    if declarator.kind() != "variable_declarator" {
+                        continue;
+                    }
+                    let Some(name_node) = declarator
+                        .child_by_field_name("name")
+                        .filter(|n| n.kind() == "identifier")
+                    else {
+                        continue;
+                    };
+                    let name = format!("{prefix}{}", node_text(&name_node, source));
+                    let value = declarator
+                        .child_by_field_name("value")
+                        .and_then(|value| unwrap_function_value(value, source, self.runner_factories));
+                    match value {
+                        Some(value) => {
+                            self.functions.push(FunctionBinding {
+                                export_name: exported.then(|| name.clone()),
+                                name,
+                                line,
+                                is_async: is_async_function(&value.function),
+                                function_directive: has_function_directive(&value.function, source),
+                                input_schema: value.input_schema,
+                            });
                         }
+                        _ if exported && is_schema_name(&name) => self.schemas.push((name, line)),
+                        _ => {}
                     }
                 }
             }
+            "internal_module" | "module" => self.collect_namespace(node, source, prefix, exported),
             _ => {}
         }
-        
-        // Recursively visit children
+    }
+
+    /// `exports.name = ...`, `module.exports.name = ...` and
+    /// `module.exports = { ... }`, unless a later `module.exports = ...`
+    /// replaces the object they were assigned to.
+    fn collect_commonjs_export(&mut self, assignment: &Node, statement: &Node, source: &str) {
+        let (Some(target), Some(value)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            return;
+        };
+        if target.kind() != "member_expression" {
+            return;
+        }
+
+        let start = statement.start_byte();
+        if is_module_exports(&target, source) {
+            if value.kind() == "object" && self.exports_reassigned_at == Some(start) {
+                let mut cursor = value.walk();
+                for property in value.named_children(&mut cursor) {
+                    self.collect_export_property(&property, source);
+                }
+            }
+        } else if target.child_by_field_name("object").is_some_and(|object| {
+            match self.exports_reassigned_at {
+                None => is_exports_identifier(&object, source) || is_module_exports(&object, source),
+                // `exports` still points at the replaced object
+                Some(reassigned_at) => start > reassigned_at && is_module_exports(&object, source),
+            }
+        }) {
+            if let Some(name) = target
+                .child_by_field_name("property")
+                .filter(|n| n.kind() == "property_identifier")
+            {
+                let name = node_text(&name, source).to_string();
+                self.collect_commonjs_value(name, value, get_line_number(statement, source), source);
+            }
+        }
+    }
+
+    /// A property of an object literal assigned to `module.exports`.
+    fn collect_export_property(&mut self, property: &Node, source: &str) {
+        match property.kind() {
+            "shorthand_property_identifier" => {
+                let name = node_text(property, source).to_string();
+                self.export_lists.push((name.clone(), name));
+            }
+            "pair" => {
+                let (Some(key), Some(value)) = (
+                    property.child_by_field_name("key"),
+                    property.child_by_field_name("value"),
+                ) else {
+                    return;
+                };
+                if key.kind() == "property_identifier" {
+                    let name = node_text(&key, source).to_string();
+                    self.collect_commonjs_value(name, value, get_line_number(&key, source), source);
+                }
+            }
+            "method_definition" => {
+                let Some(key) = property
+                    .child_by_field_name("name")
+                    .filter(|n| n.kind() == "property_identifier")
+                else {
+                    return;
+                };
+                let name = node_text(&key, source).to_string();
+                self.functions.push(FunctionBinding {
+                    export_name: Some(name.clone()),
+                    name,
+                    line: get_line_number(&key, source),
+                    is_async: is_async_function(property),
+                    function_directive: has_function_directive(property, source),
+                    input_schema: None,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// A value exported under `name` through CommonJS. Identifiers export a
+    /// local binding, like `export { local as name }`.
+    fn collect_commonjs_value(&mut self, name: String, value: Node, line: usize, source: &str) {
+        if value.kind() == "identifier" {
+            self.export_lists.push((node_text(&value, source).to_string(), name));
+        } else if let Some(value) = unwrap_function_value(value, source, self.runner_factories) {
+            self.functions.push(FunctionBinding {
+                export_name: Some(name.clone()),
+                name,
+                line,
+                is_async: is_async_function(&value.function),
+                function_directive: has_function_directive(&value.function, source),
+                input_schema: value.input_schema,
+            });
+        } else if is_schema_name(&name) {
+            self.schemas.push((name, line));
+        }
+    }
+
+    fn collect_namespace(&mut self, namespace: &Node, source: &str, prefix: &str, exported: bool) {
+        let (Some(name), Some(body)) = (
+            namespace.child_by_field_name("name"),
+            namespace.child_by_field_name("body"),
+        ) else {
+            return;
+        };
+        let prefix = format!("{prefix}{}.", node_text(&name, source));
+        self.collect_block(&body, source, &prefix, exported);
+    }
+}
+
+/// Finds exported async runner functions and exported schema variables
+/// using tree-sitter AST parsing.
+///
+/// Only module-scope declarations are considered (see [`ModuleScope::collect`]).
+/// Covers `export function`/`export const` declarations, default exports,
+/// local `export { name as alias }` lists and CommonJS `exports.name`,
+/// `module.exports.name` and `module.exports = { ... }` assignments. A named
+/// default export keeps its own name; an anonymous one is reported as
+/// `default`. Re-exports from other modules are left to the module that
+/// declares them. Values are unwrapped as in [`unwrap_function_value`], so
+/// calls to `runner_factories` count and record their schema argument.
+pub fn find_exports(
+    root_node: &Node,
+    content: &str,
+    runner_factories: &[String],
+) -> (Vec<RunnerInfo>, Vec<SchemaInfo>) {
+    let mut runners = Vec::new();
+
+    let module_directive = has_module_directive(root_node, content);
+    let scope = ModuleScope::collect(root_node, content, runner_factories);
+
+    for binding in &scope.functions {
+        let export_names = binding.export_name.iter().cloned().chain(
+            scope
+                .export_lists
+                .iter()
+                .filter(|(local, _)| *local == binding.name)
+                .map(|(_, exported)| exported.clone()),
+        );
+        for name in export_names {
+            let candidate = RunnerCandidate {
+                exported: true,
+                is_async: binding.is_async,
+                module_directive,
+                function_directive: binding.function_directive,
+            };
+            if candidate.is_runner() {
+                runners.push(RunnerInfo {
+                    name,
+                    line: binding.line,
+                    input_schema: binding.input_schema.clone(),
+                });
+            }
+        }
+    }
+    runners.sort_by_key(|runner| runner.line);
+
+    let schemas = scope
+        .schemas
+        .into_iter()
+        .map(|(name, line)| SchemaInfo {
+            runner_name: schema_runner_name(&name, &runners).map(str::to_string),
+            name,
+            line,
+        })
+        .collect();
+
+    (runners, schemas)
+}
+
+/// Finds misspelled `"use runner"` directives in the module prologue and in
+/// function body prologues, within `max_distance` edits (see
+/// [`is_directive_typo`]).
+pub fn find_misspelled_directives(
+    root_node: &Node,
+    content: &str,
+    max_distance: usize,
+) -> Vec<MisspelledDirective> {
+    let mut typos = Vec::new();
+
+    fn check_prologue(
+        block: &Node,
+        content: &str,
+        max_distance: usize,
+        typos: &mut Vec<MisspelledDirective>,
+    ) {
+        for (statement, value) in prologue_directives(block, content) {
+            if is_directive_typo(&value, max_distance) {
+                typos.push(MisspelledDirective {
+                    range: node_range(&statement, content),
+                    directive: value,
+                });
+            }
+        }
+    }
+
+    fn walk_node(
+        node: Node,
+        content: &str,
+        max_distance: usize,
+        typos: &mut Vec<MisspelledDirective>,
+    ) {
+        if node.kind() == "statement_block" && node.parent().is_some_and(|p| is_function_like(&p)) {
+            check_prologue(&node, content, max_distance, typos);
+        }
+
         let mut cursor = node.walk();
         if cursor.goto_first_child() {
             loop {
-                walk_node(cursor.node(), content, schemas, runner_names);
+                walk_node(cursor.node(), content, max_distance, typos);
                 if !cursor.goto_next_sibling() {
                     break;
                 }
             }
         }
     }
-    
-    walk_node(root_node, content, &mut schemas, runner_names);
-    
-    schemas
-}
 
+    check_prologue(root_node, content, max_distance, &mut typos);
+    walk_node(*root_node, content, max_distance, &mut typos);
+
+    typos
+}
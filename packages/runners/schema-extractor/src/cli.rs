@@ -1,20 +1,74 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use schema_extractor::diagnostics::DiagnosticsFormat;
+use schema_extractor::{
+    DEFAULT_PATTERNS, DEFAULT_RUNNER_FACTORIES, DEFAULT_TYPO_THRESHOLD, FORMAT_VERSION,
+    LEGACY_FORMAT_VERSION,
+};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "schema-extractor")]
-#[command(about = "Extracts runner and schema metadata from TypeScript files")]
+#[command(about = "Extracts runner and schema metadata from TypeScript and JavaScript files")]
 pub struct Args {
     /// Glob pattern(s) to match runner files
-    #[arg(short, long, default_value = "src/**/*.ts,runners/**/*.ts")]
-    pub patterns: String,
+    #[arg(short, long, value_delimiter = ',', default_values = DEFAULT_PATTERNS)]
+    pub patterns: Vec<String>,
+
+    /// Function(s) wrapping runners, e.g. `defineRunner(schema, async (ctx, input) => {})`
+    #[arg(long, value_delimiter = ',', default_values = DEFAULT_RUNNER_FACTORIES)]
+    pub runner_factories: Vec<String>,
+
+    /// Maximum edit distance at which a directive is reported as a misspelled "use runner"
+    #[arg(long, default_value_t = DEFAULT_TYPO_THRESHOLD)]
+    pub typo_threshold: usize,
 
     /// Output file path for metadata JSON
     #[arg(short, long, default_value = "runner-schemas.json")]
     pub output: String,
 
-    /// Working directory
+    /// Working directory; patterns and emitted paths are relative to it
     #[arg(short, long, default_value = ".")]
-    pub cwd: String,
+    pub cwd: PathBuf,
+
+    /// Output format version (1 emits the legacy bare array)
+    #[arg(
+        long,
+        default_value_t = FORMAT_VERSION,
+        value_parser = clap::value_parser!(u32)
+            .range(LEGACY_FORMAT_VERSION as i64..=FORMAT_VERSION as i64)
+    )]
+    pub format_version: u32,
+
+    /// How diagnostics are printed
+    #[arg(long, value_enum, default_value_t = DiagnosticsFormatArg::Human)]
+    pub diagnostics_format: DiagnosticsFormatArg,
+
+    /// Exit with a non-zero status on warnings as well as errors
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Print the JSON Schema of the output envelope and exit
+    #[arg(long)]
+    pub print_schema: bool,
 }
 
+/// `--diagnostics-format` values, mapped onto [`DiagnosticsFormat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiagnosticsFormatArg {
+    /// JSON array on stderr
+    Json,
+    /// `file:line:column: severity[code]: message` lines on stderr
+    Human,
+    /// GitHub Actions workflow commands on stdout
+    Github,
+}
 
+impl From<DiagnosticsFormatArg> for DiagnosticsFormat {
+    fn from(format: DiagnosticsFormatArg) -> Self {
+        match format {
+            DiagnosticsFormatArg::Json => DiagnosticsFormat::Json,
+            DiagnosticsFormatArg::Human => DiagnosticsFormat::Human,
+            DiagnosticsFormatArg::Github => DiagnosticsFormat::Github,
+        }
+    }
+}
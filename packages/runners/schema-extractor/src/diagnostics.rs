@@ -0,0 +1,161 @@
+use crate::types::{Diagnostic, DiagnosticCode, Range, SchemaMetadata, Severity};
+use runners_model::DEFAULT_EXPORT_NAME;
+use std::collections::HashMap;
+
+/// How diagnostics are reported on the console.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+    /// JSON array on stderr
+    Json,
+    /// `file:line:column: severity[code]: message` lines on stderr
+    Human,
+    /// GitHub Actions workflow commands on stdout
+    Github,
+}
+
+impl Diagnostic {
+    pub fn new(
+        file: impl Into<String>,
+        range: Option<Range>,
+        severity: Severity,
+        code: DiagnosticCode,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: file.into(),
+            range,
+            severity,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// Report schemas that were not associated with any runner in their file.
+pub fn check_unmatched_schemas(metadata: &SchemaMetadata, diagnostics: &mut Vec<Diagnostic>) {
+    for schema in metadata.schemas.iter().filter(|s| s.runner_name.is_none()) {
+        diagnostics.push(Diagnostic::new(
+            &metadata.file,
+            Some(Range::line(schema.line)),
+            Severity::Warning,
+            DiagnosticCode::UnmatchedSchema,
+            format!("Schema \"{}\" does not match any runner in this file", schema.name),
+        ));
+    }
+}
+
+/// Report runner names exported more than once across all extracted files.
+///
+/// Runners are registered by name, so a duplicate silently shadows the first
+/// definition at runtime. Anonymous default exports are skipped: they are
+/// registered under whatever name the importing module gives them, not as
+/// `default`.
+pub fn check_duplicate_runners(files: &[SchemaMetadata], diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen: HashMap<&str, (&str, usize)> = HashMap::new();
+    for metadata in files {
+        for runner in metadata
+            .runners
+            .iter()
+            .filter(|runner| runner.name != DEFAULT_EXPORT_NAME)
+        {
+            match seen.get(runner.name.as_str()) {
+                Some((first_file, first_line)) => diagnostics.push(Diagnostic::new(
+                    &metadata.file,
+                    Some(Range::line(runner.line)),
+                    Severity::Error,
+                    DiagnosticCode::DuplicateRunner,
+                    format!(
+                        "Runner \"{}\" is already defined at {}:{}",
+                        runner.name, first_file, first_line
+                    ),
+                )),
+                None => {
+                    seen.insert(&runner.name, (&metadata.file, runner.line));
+                }
+            }
+        }
+    }
+}
+
+/// Whether the diagnostics should fail the run.
+pub fn should_fail(diagnostics: &[Diagnostic], strict: bool) -> bool {
+    diagnostics
+        .iter()
+        .any(|d| d.severity == Severity::Error || (strict && d.severity == Severity::Warning))
+}
+
+/// Print diagnostics in the requested format.
+pub fn report(diagnostics: &[Diagnostic], format: DiagnosticsFormat) {
+    match format {
+        DiagnosticsFormat::Json => match serde_json::to_string_pretty(diagnostics) {
+            Ok(json) => eprintln!("{}", json),
+            Err(e) => eprintln!("Failed to serialize diagnostics: {}", e),
+        },
+        DiagnosticsFormat::Human => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", format_human(diagnostic));
+            }
+        }
+        DiagnosticsFormat::Github => {
+            for diagnostic in diagnostics {
+                println!("{}", format_github(diagnostic));
+            }
+        }
+    }
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+/// `file:line:column: severity[code]: message`
+pub fn format_human(diagnostic: &Diagnostic) -> String {
+    let location = match diagnostic.range {
+        Some(range) => format!(
+            "{}:{}:{}",
+            diagnostic.file, range.start.line, range.start.column
+        ),
+        None => diagnostic.file.clone(),
+    };
+    format!(
+        "{}: {}[{}]: {}",
+        location,
+        severity_label(diagnostic.severity),
+        diagnostic.code.as_str(),
+        diagnostic.message
+    )
+}
+
+/// Format as a GitHub Actions `::error`/`::warning` workflow command.
+pub fn format_github(diagnostic: &Diagnostic) -> String {
+    let mut properties = vec![format!("file={}", escape_github_property(&diagnostic.file))];
+    if let Some(range) = diagnostic.range {
+        properties.push(format!("line={}", range.start.line));
+        properties.push(format!("col={}", range.start.column));
+        properties.push(format!("endLine={}", range.end.line));
+        properties.push(format!("endColumn={}", range.end.column));
+    }
+    properties.push(format!("title={}", diagnostic.code.as_str()));
+    format!(
+        "::{} {}::{}",
+        severity_label(diagnostic.severity),
+        properties.join(","),
+        escape_github_data(&diagnostic.message)
+    )
+}
+
+fn escape_github_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_github_property(value: &str) -> String {
+    escape_github_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
@@ -1,41 +1,250 @@
-use crate::ast::{find_exported_runners, find_exported_schemas};
-use crate::types::SchemaMetadata;
+use crate::ast::{
+    find_exports, find_misspelled_directives, find_syntax_errors, has_runner_directive, parse,
+    Dialect,
+};
+use crate::diagnostics::check_unmatched_schemas;
+use crate::types::{Diagnostic, DiagnosticCode, SchemaMetadata, Severity};
+use runners_model::{is_directive_typo, DEFAULT_RUNNER_FACTORIES, DEFAULT_TYPO_THRESHOLD};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path};
+use tree_sitter::{Node, Tree};
 
-/// Check if content has "use runner" directive
-pub fn has_use_runner_directive(content: &str) -> bool {
-    // Check for module-level or function-level "use runner" directive
-    content.contains("\"use runner\"") || content.contains("'use runner'")
+/// Check if content has a "use runner" directive in the module prologue or a
+/// function body prologue. `path` picks the grammar, as in [`extract_file`].
+pub fn has_use_runner_directive(content: &str, path: &Path) -> bool {
+    runner_source_tree(content, Dialect::from_path(path)).is_some()
 }
 
-/// Process a single file and extract metadata
-pub fn process_file(file_path: &PathBuf) -> Option<SchemaMetadata> {
+/// The parsed file if it has a `"use runner"` directive, so files that do are
+/// only parsed once.
+fn runner_source_tree(content: &str, dialect: Dialect) -> Option<Tree> {
+    if !may_have_runner_directive(content, None) {
+        return None;
+    }
+    parse(content, dialect).filter(|tree| has_runner_directive(&tree.root_node(), content))
+}
+
+/// Whether `content` may have a `"use runner"` directive or, given a
+/// `typo_threshold`, a misspelling of one, so most files are ruled out
+/// without parsing.
+fn may_have_runner_directive(content: &str, typo_threshold: Option<usize>) -> bool {
+    // Every spelling of the directive either contains it literally or uses an
+    // escape sequence
+    if content.contains("use runner") || content.contains('\\') {
+        return true;
+    }
+    typo_threshold.is_some_and(|threshold| {
+        content
+            .lines()
+            .flat_map(string_literals)
+            .any(|value| is_directive_typo(value, threshold))
+    })
+}
+
+/// The contents of the single or double quoted strings on `line`, scanning
+/// past each closing quote so apostrophes inside strings are skipped.
+fn string_literals(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        let start = rest.find(['"', '\''])?;
+        let quote = rest[start..].chars().next()?;
+        let value = &rest[start + 1..];
+        let end = value.find(quote)?;
+        rest = &value[end + 1..];
+        Some(&value[..end])
+    })
+}
+
+/// Render `path` relative to `root` with forward slashes.
+///
+/// Paths outside of `root` are kept as-is (still normalized) so they are never
+/// silently rewritten to point at the wrong file.
+pub fn relative_path(root: &Path, path: &Path) -> String {
+    match path.strip_prefix(root) {
+        Ok(relative) => relative
+            .components()
+            .filter(|c| !matches!(c, Component::CurDir))
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+        Err(_) => path.to_string_lossy().replace('\\', "/"),
+    }
+}
+
+/// Process a single file and extract metadata, collecting any problems into `diagnostics`
+pub fn process_file(
+    root: &Path,
+    file_path: &Path,
+    runner_factories: &[String],
+    typo_threshold: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<SchemaMetadata> {
+    let file = relative_path(root, file_path);
+
     let content = match fs::read_to_string(file_path) {
         Ok(c) => c,
         Err(e) => {
-            eprintln!("Warning: Could not read file {:?}: {}", file_path, e);
+            diagnostics.push(Diagnostic::new(
+                file,
+                None,
+                Severity::Error,
+                DiagnosticCode::UnreadableFile,
+                format!("Could not read file: {}", e),
+            ));
             return None;
         }
     };
 
     // Only process files with "use runner" directive
-    if !has_use_runner_directive(&content) {
+    if !may_have_runner_directive(&content, Some(typo_threshold)) {
         return None;
     }
+    let Some(tree) = parse(&content, Dialect::from_path(file_path)) else {
+        // Whether the file has runners is unknown, so it is kept as partial
+        // rather than dropped
+        return Some(unparsed_file(file, diagnostics));
+    };
+    if !has_runner_directive(&tree.root_node(), &content) {
+        // A file whose only directive is misspelled has no runners, but the
+        // typo is still reported, as the SWC plugin does
+        check_misspelled_directives(
+            &tree.root_node(),
+            &content,
+            &file,
+            typo_threshold,
+            diagnostics,
+        );
+        return None;
+    }
+    Some(extract_tree(
+        &tree,
+        &content,
+        file,
+        runner_factories,
+        typo_threshold,
+        diagnostics,
+    ))
+}
+
+/// Extract runner and schema metadata from in-memory source.
+///
+/// `path` picks the grammar (see [`Dialect::from_path`]) and is recorded as
+/// the metadata's `file`. Runner factories are the defaults from
+/// [`DEFAULT_RUNNER_FACTORIES`]. Diagnostics are discarded; use
+/// [`extract_file_with_diagnostics`] to keep them.
+pub fn extract_file(content: &str, path: &Path) -> SchemaMetadata {
+    let runner_factories = default_runner_factories();
+    extract_file_with_diagnostics(
+        content,
+        path,
+        &runner_factories,
+        DEFAULT_TYPO_THRESHOLD,
+        &mut Vec::new(),
+    )
+}
+
+/// Like [`extract_file`], with the given runner factory names and collecting
+/// syntax errors, misspelled directives and unmatched schemas into
+/// `diagnostics`. Directives within `typo_threshold` edits of `"use runner"`
+/// are reported as misspelled, as with the SWC plugin's `typoThreshold`.
+pub fn extract_file_with_diagnostics(
+    content: &str,
+    path: &Path,
+    runner_factories: &[String],
+    typo_threshold: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> SchemaMetadata {
+    let file = path.to_string_lossy().replace('\\', "/");
+    match parse(content, Dialect::from_path(path)) {
+        Some(tree) => extract_tree(
+            &tree,
+            content,
+            file,
+            runner_factories,
+            typo_threshold,
+            diagnostics,
+        ),
+        None => unparsed_file(file, diagnostics),
+    }
+}
+
+/// Empty, partial metadata for a file tree-sitter gave up on
+fn unparsed_file(file: String, diagnostics: &mut Vec<Diagnostic>) -> SchemaMetadata {
+    diagnostics.push(Diagnostic::new(
+        &file,
+        None,
+        Severity::Error,
+        DiagnosticCode::ParseError,
+        "File could not be parsed; extracted metadata may be incomplete",
+    ));
+    SchemaMetadata {
+        file,
+        runners: Vec::new(),
+        schemas: Vec::new(),
+        partial: true,
+    }
+}
 
-    let runners = find_exported_runners(&content);
-    let runner_names: Vec<String> = runners.iter().map(|r| r.name.clone()).collect();
-    let schemas = find_exported_schemas(&content, &runner_names);
+/// [`DEFAULT_RUNNER_FACTORIES`] as owned strings
+pub(crate) fn default_runner_factories() -> Vec<String> {
+    DEFAULT_RUNNER_FACTORIES.iter().map(|f| f.to_string()).collect()
+}
 
-    // Normalize path separators
-    let file_str = file_path.to_string_lossy().replace('\\', "/");
+fn extract_tree(
+    tree: &Tree,
+    content: &str,
+    file: String,
+    runner_factories: &[String],
+    typo_threshold: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> SchemaMetadata {
+    let root = tree.root_node();
 
-    Some(SchemaMetadata {
-        file: file_str,
+    // Runners next to a syntax error may be missing, so the file is flagged
+    // as partial for consumers that refuse to deploy incomplete metadata
+    let syntax_errors = find_syntax_errors(&root, content);
+    for error in &syntax_errors {
+        diagnostics.push(Diagnostic::new(
+            &file,
+            Some(error.range),
+            Severity::Error,
+            DiagnosticCode::ParseError,
+            format!("{}; extracted metadata may be incomplete", error.message),
+        ));
+    }
+
+    check_misspelled_directives(&root, content, &file, typo_threshold, diagnostics);
+
+    let (runners, schemas) = find_exports(&root, content, runner_factories);
+
+    let metadata = SchemaMetadata {
+        file,
         runners,
         schemas,
-    })
-}
+        partial: !syntax_errors.is_empty(),
+    };
+    check_unmatched_schemas(&metadata, diagnostics);
 
+    metadata
+}
 
+fn check_misspelled_directives(
+    root: &Node,
+    content: &str,
+    file: &str,
+    typo_threshold: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for typo in find_misspelled_directives(root, content, typo_threshold) {
+        diagnostics.push(Diagnostic::new(
+            file,
+            Some(typo.range),
+            Severity::Warning,
+            DiagnosticCode::MisspelledDirective,
+            format!(
+                "\"{}\" looks like a typo. Did you mean \"use runner\"?",
+                typo.directive
+            ),
+        ));
+    }
+}
@@ -0,0 +1,37 @@
+//! Build-time extraction of runner and schema metadata.
+//!
+//! This crate backs the `schema-extractor` CLI and can be embedded directly in
+//! Rust build tooling and test harnesses:
+//!
+//! ```no_run
+//! use schema_extractor::{extract_project, ExtractOptions};
+//!
+//! let extraction = extract_project(&ExtractOptions {
+//!     root: "path/to/project".into(),
+//!     ..Default::default()
+//! })
+//! .expect("extraction failed");
+//!
+//! for file in &extraction.files {
+//!     for runner in &file.runners {
+//!         println!("{}:{} {}", file.file, runner.line, runner.name);
+//!     }
+//! }
+//! ```
+
+mod ast;
+pub mod diagnostics;
+mod file;
+mod project;
+pub mod types;
+
+pub use ast::Dialect;
+pub use file::{extract_file, extract_file_with_diagnostics, has_use_runner_directive};
+pub use project::{
+    extract_project, extract_project_with, ExtractError, ExtractOptions, DEFAULT_PATTERNS,
+};
+pub use runners_model::{DEFAULT_RUNNER_FACTORIES, DEFAULT_TYPO_THRESHOLD};
+pub use types::{
+    Diagnostic, DiagnosticCode, Extraction, GeneratorInfo, LegacyRunnerInfo, LegacySchemaMetadata,
+    Position, Range, RunnerInfo, SchemaInfo, SchemaMetadata, Severity, ENVELOPE_JSON_SCHEMA, FORMAT_VERSION, LEGACY_FORMAT_VERSION,
+};
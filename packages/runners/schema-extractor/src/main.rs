@@ -1,54 +1,35 @@
-mod ast;
 mod cli;
-mod file;
-mod types;
 
 use clap::Parser;
 use cli::Args;
-use file::process_file;
-use glob::glob;
+use schema_extractor::diagnostics::{report, should_fail};
+use schema_extractor::{
+    extract_project, ExtractOptions, ENVELOPE_JSON_SCHEMA, LEGACY_FORMAT_VERSION,
+};
 use std::fs;
 use std::path::PathBuf;
-use types::SchemaMetadata;
 
 fn main() {
     let args = Args::parse();
 
-    let patterns: Vec<&str> = args.patterns.split(',').map(|s| s.trim()).collect();
-    let mut all_metadata: Vec<SchemaMetadata> = Vec::new();
-
-    for pattern in patterns {
-        let full_pattern = if args.cwd != "." {
-            format!("{}/{}", args.cwd, pattern)
-        } else {
-            pattern.to_string()
-        };
-
-        match glob(&full_pattern) {
-            Ok(paths) => {
-                for entry in paths {
-                    match entry {
-                        Ok(path) => {
-                            // Skip node_modules, dist, .nitro
-                            let path_str = path.to_string_lossy().replace('\\', "/");
-                            if path_str.contains("node_modules")
-                                || path_str.contains("/dist/")
-                                || path_str.contains("/.nitro/")
-                            {
-                                continue;
-                            }
+    if args.print_schema {
+        print!("{}", ENVELOPE_JSON_SCHEMA);
+        return;
+    }
 
-                            if let Some(metadata) = process_file(&path) {
-                                all_metadata.push(metadata);
-                            }
-                        }
-                        Err(e) => eprintln!("Error reading path: {}", e),
-                    }
-                }
-            }
-            Err(e) => eprintln!("Invalid glob pattern {}: {}", pattern, e),
+    let mut extraction = match extract_project(&ExtractOptions {
+        root: args.cwd,
+        patterns: args.patterns,
+        runner_factories: args.runner_factories,
+        typo_threshold: args.typo_threshold,
+    }) {
+        Ok(extraction) => extraction,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
         }
-    }
+    };
+    extraction.version = args.format_version;
 
     // Write output JSON
     let output_path = PathBuf::from(&args.output);
@@ -59,13 +40,19 @@ fn main() {
         }
     }
 
-    match serde_json::to_string_pretty(&all_metadata) {
+    let serialized = if args.format_version == LEGACY_FORMAT_VERSION {
+        serde_json::to_string_pretty(&extraction.legacy_files())
+    } else {
+        serde_json::to_string_pretty(&extraction)
+    };
+
+    match serialized {
         Ok(json) => {
             if let Err(e) = fs::write(&output_path, json) {
                 eprintln!("Failed to write output file: {}", e);
                 std::process::exit(1);
             }
-            println!("Extracted metadata from {} files", all_metadata.len());
+            println!("Extracted metadata from {} files", extraction.files.len());
             println!("Output written to: {}", output_path.display());
         }
         Err(e) => {
@@ -73,4 +60,9 @@ fn main() {
             std::process::exit(1);
         }
     }
+
+    report(&extraction.diagnostics, args.diagnostics_format.into());
+    if should_fail(&extraction.diagnostics, args.strict) {
+        std::process::exit(1);
+    }
 }
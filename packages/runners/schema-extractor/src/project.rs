@@ -0,0 +1,188 @@
+use crate::diagnostics::check_duplicate_runners;
+use crate::file::{default_runner_factories, process_file, relative_path};
+use crate::types::{
+    Diagnostic, DiagnosticCode, Extraction, GeneratorInfo, SchemaMetadata, Severity, FORMAT_VERSION,
+};
+use glob::{glob, Pattern};
+use runners_model::DEFAULT_TYPO_THRESHOLD;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+
+/// Runner sources in `src/` and `runners/`, for every extension the extractor parses
+pub const DEFAULT_PATTERNS: &[&str] = &[
+    "src/**/*.ts",
+    "src/**/*.tsx",
+    "src/**/*.mts",
+    "src/**/*.cts",
+    "src/**/*.js",
+    "src/**/*.jsx",
+    "src/**/*.mjs",
+    "src/**/*.cjs",
+    "runners/**/*.ts",
+    "runners/**/*.tsx",
+    "runners/**/*.mts",
+    "runners/**/*.cts",
+    "runners/**/*.js",
+    "runners/**/*.jsx",
+    "runners/**/*.mjs",
+    "runners/**/*.cjs",
+];
+
+/// Options for [`extract_project`].
+#[derive(Debug, Clone)]
+pub struct ExtractOptions {
+    /// Project root; patterns are resolved against it and emitted paths are relative to it
+    pub root: PathBuf,
+    /// Glob patterns matching runner files
+    pub patterns: Vec<String>,
+    /// Calls whose last argument is a runner function, e.g. `defineRunner`
+    pub runner_factories: Vec<String>,
+    /// Maximum edit distance at which a directive is reported as a misspelled
+    /// `"use runner"`; keep it in line with the SWC plugin's `typoThreshold`
+    pub typo_threshold: usize,
+}
+
+impl Default for ExtractOptions {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("."),
+            patterns: DEFAULT_PATTERNS.iter().map(|p| p.to_string()).collect(),
+            runner_factories: default_runner_factories(),
+            typo_threshold: DEFAULT_TYPO_THRESHOLD,
+        }
+    }
+}
+
+/// Errors that prevent extraction from running at all.
+///
+/// Problems with individual files are reported as [`Diagnostic`]s instead.
+#[derive(Debug)]
+pub enum ExtractError {
+    /// The root directory does not exist or cannot be resolved
+    InvalidRoot {
+        root: PathBuf,
+        source: std::io::Error,
+    },
+    /// A pattern is not a valid glob
+    InvalidPattern {
+        pattern: String,
+        source: glob::PatternError,
+    },
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::InvalidRoot { root, source } => {
+                write!(
+                    f,
+                    "Invalid working directory {}: {}",
+                    root.display(),
+                    source
+                )
+            }
+            ExtractError::InvalidPattern { pattern, source } => {
+                write!(f, "Invalid glob pattern {}: {}", pattern, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExtractError::InvalidRoot { source, .. } => Some(source),
+            ExtractError::InvalidPattern { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Extract metadata from every runner file under `options.root` matching `options.patterns`.
+///
+/// Files without a `"use runner"` directive are skipped (a misspelled one is
+/// still reported), as are anything below `node_modules`, `dist` and `.nitro`.
+pub fn extract_project(options: &ExtractOptions) -> Result<Extraction, ExtractError> {
+    extract_project_with(options, |_| {})
+}
+
+/// Like [`extract_project`], calling `on_file` as soon as each file has been extracted.
+///
+/// Lets callers stream results (for example to a dev server) instead of
+/// waiting for the whole project. Files are also collected in the returned
+/// [`Extraction`].
+pub fn extract_project_with(
+    options: &ExtractOptions,
+    mut on_file: impl FnMut(&SchemaMetadata),
+) -> Result<Extraction, ExtractError> {
+    let root = fs::canonicalize(&options.root).map_err(|source| ExtractError::InvalidRoot {
+        root: options.root.clone(),
+        source,
+    })?;
+    let root_pattern = Pattern::escape(&root.to_string_lossy().replace('\\', "/"));
+
+    let mut all_metadata: Vec<SchemaMetadata> = Vec::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    // Overlapping patterns must not report the same file (and its runners) twice
+    let mut seen_paths: HashSet<PathBuf> = HashSet::new();
+
+    for pattern in &options.patterns {
+        let pattern = pattern.trim();
+        // Patterns are always resolved against the root so emitted paths can
+        // be made relative to it regardless of how the tool was invoked
+        let full_pattern = format!("{}/{}", root_pattern, pattern.trim_start_matches("./"));
+
+        let paths = glob(&full_pattern).map_err(|source| ExtractError::InvalidPattern {
+            pattern: pattern.to_string(),
+            source,
+        })?;
+
+        for entry in paths {
+            match entry {
+                Ok(path) => {
+                    // Skip node_modules, dist, .nitro (checked below the root only)
+                    let path_str = format!("/{}", relative_path(&root, &path));
+                    if path_str.contains("node_modules")
+                        || path_str.contains("/dist/")
+                        || path_str.contains("/.nitro/")
+                    {
+                        continue;
+                    }
+
+                    if !seen_paths.insert(path.clone()) {
+                        continue;
+                    }
+
+                    if let Some(metadata) = process_file(
+                        &root,
+                        &path,
+                        &options.runner_factories,
+                        options.typo_threshold,
+                        &mut diagnostics,
+                    ) {
+                        on_file(&metadata);
+                        all_metadata.push(metadata);
+                    }
+                }
+                Err(e) => diagnostics.push(Diagnostic::new(
+                    relative_path(&root, e.path()),
+                    None,
+                    Severity::Error,
+                    DiagnosticCode::UnreadableFile,
+                    format!("Could not read path: {}", e.error()),
+                )),
+            }
+        }
+    }
+
+    check_duplicate_runners(&all_metadata, &mut diagnostics);
+
+    Ok(Extraction {
+        version: FORMAT_VERSION,
+        generator: GeneratorInfo::current(),
+        root: root.to_string_lossy().replace('\\', "/"),
+        files: all_metadata,
+        diagnostics,
+    })
+}
@@ -1,23 +1,145 @@
 use serde::{Deserialize, Serialize};
 
+pub use runners_model::{RunnerInfo, SchemaInfo, SchemaMetadata};
+
+/// Format version of the `runner-schemas.json` envelope.
+pub const FORMAT_VERSION: u32 = 2;
+
+/// Format version of the bare `Vec<SchemaMetadata>` array emitted before the envelope existed.
+pub const LEGACY_FORMAT_VERSION: u32 = 1;
+
+/// JSON Schema describing the envelope, printed by `--print-schema`.
+pub const ENVELOPE_JSON_SCHEMA: &str = include_str!("../schema/runner-schemas.schema.json");
+
+/// Result of a project extraction; also the top-level shape of `runner-schemas.json`.
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SchemaMetadata {
-    pub file: String,
-    pub runners: Vec<RunnerInfo>,
-    pub schemas: Vec<SchemaInfo>,
+pub struct Extraction {
+    pub version: u32,
+    pub generator: GeneratorInfo,
+    /// Absolute path of the extraction root; every `file` is relative to it
+    pub root: String,
+    pub files: Vec<SchemaMetadata>,
+    pub diagnostics: Vec<Diagnostic>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct RunnerInfo {
-    pub name: String,
+impl Extraction {
+    /// The files as written by format version 1, without the fields added since.
+    pub fn legacy_files(&self) -> Vec<LegacySchemaMetadata<'_>> {
+        self.files
+            .iter()
+            .map(|metadata| LegacySchemaMetadata {
+                file: &metadata.file,
+                runners: metadata
+                    .runners
+                    .iter()
+                    .map(|runner| LegacyRunnerInfo {
+                        name: &runner.name,
+                        line: runner.line,
+                    })
+                    .collect(),
+                schemas: &metadata.schemas,
+            })
+            .collect()
+    }
+}
+
+/// [`SchemaMetadata`] in the legacy bare-array format.
+#[derive(Debug, Serialize)]
+pub struct LegacySchemaMetadata<'a> {
+    pub file: &'a str,
+    pub runners: Vec<LegacyRunnerInfo<'a>>,
+    pub schemas: &'a [SchemaInfo],
+}
+
+/// [`RunnerInfo`] in the legacy bare-array format.
+#[derive(Debug, Serialize)]
+pub struct LegacyRunnerInfo<'a> {
+    pub name: &'a str,
     pub line: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct SchemaInfo {
+pub struct GeneratorInfo {
     pub name: String,
-    pub runner_name: Option<String>,
-    pub line: usize,
+    pub version: String,
+}
+
+impl GeneratorInfo {
+    /// Generator info for this build of the extractor.
+    pub fn current() -> Self {
+        Self {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
 }
 
+/// A problem found while extracting metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// Path relative to the extraction root
+    pub file: String,
+    /// Location in the file, if the problem can be pinned to one
+    pub range: Option<Range>,
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticCode {
+    /// The file matched a pattern but could not be read
+    UnreadableFile,
+    /// tree-sitter reported syntax errors in the file
+    ParseError,
+    /// A schema export could not be associated with any runner
+    UnmatchedSchema,
+    /// The same runner name is exported more than once
+    DuplicateRunner,
+    /// A directive prologue string looks like a misspelled `"use runner"`
+    MisspelledDirective,
+}
+
+impl DiagnosticCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiagnosticCode::UnreadableFile => "unreadable-file",
+            DiagnosticCode::ParseError => "parse-error",
+            DiagnosticCode::UnmatchedSchema => "unmatched-schema",
+            DiagnosticCode::DuplicateRunner => "duplicate-runner",
+            DiagnosticCode::MisspelledDirective => "misspelled-directive",
+        }
+    }
+}
+
+/// 1-based line/column span in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Range {
+    pub start: Position,
+    pub end: Position,
+}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Range {
+    /// Range covering the start of `line`, for items only tracked by line.
+    pub fn line(line: usize) -> Self {
+        let position = Position { line, column: 1 };
+        Self {
+            start: position,
+            end: position,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use schema_extractor::{
+    extract_file, extract_file_with_diagnostics, extract_project, has_use_runner_directive,
+    ExtractOptions, DEFAULT_TYPO_THRESHOLD,
+};
+use std::path::Path;
+
+#[test]
+fn extract_file_reads_in_memory_source() {
+    let source = r#"
+"use runner";
+export const widgetSchema = z.object({});
+export async function widget(ctx) {
+  return <div>{ctx.name}</div>;
+}
+"#;
+
+    let metadata = extract_file(source, Path::new("runners/widget.tsx"));
+
+    assert_eq!(metadata.file, "runners/widget.tsx");
+    assert!(!metadata.partial);
+    assert_eq!(metadata.runners.len(), 1);
+    assert_eq!(metadata.runners[0].name, "widget");
+    assert_eq!(metadata.runners[0].line, 4);
+    assert_eq!(metadata.schemas[0].runner_name.as_deref(), Some("widget"));
+}
+
+#[test]
+fn extract_project_returns_paths_relative_to_root() {
+    let extraction = extract_project(&ExtractOptions {
+        root: Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture/project"),
+        patterns: vec!["**/*.ts".to_string()],
+        ..Default::default()
+    })
+    .expect("extraction should succeed");
+
+    let files: Vec<&str> = extraction.files.iter().map(|f| f.file.as_str()).collect();
+    assert_eq!(files, ["runners/fetch.ts"]);
+    assert!(extraction.diagnostics.is_empty());
+    assert!(Path::new(&extraction.root).is_absolute());
+}
+
+#[test]
+fn extract_project_rejects_missing_root() {
+    let result = extract_project(&ExtractOptions {
+        root: Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture/missing"),
+        ..Default::default()
+    });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn has_use_runner_directive_only_checks_prologues() {
+    let path = Path::new("runners/check.ts");
+
+    assert!(has_use_runner_directive("'use runner';\nexport async function a() {}", path));
+    assert!(has_use_runner_directive("\"use strict\";\n\"use runner\";", path));
+    assert!(has_use_runner_directive(
+        "export async function a() {\n  \"use\\x20runner\";\n}",
+        path
+    ));
+
+    assert!(!has_use_runner_directive("// \"use runner\";\nexport async function a() {}", path));
+    assert!(!has_use_runner_directive("`use runner`;\nexport async function a() {}", path));
+    assert!(!has_use_runner_directive("const x = 1;\n\"use runner\";", path));
+}
+
+fn names(metadata: &schema_extractor::SchemaMetadata) -> (Vec<&str>, Vec<&str>) {
+    (
+        metadata.runners.iter().map(|r| r.name.as_str()).collect(),
+        metadata.schemas.iter().map(|s| s.name.as_str()).collect(),
+    )
+}
+
+#[test]
+fn nested_declarations_are_not_runners_or_schemas() {
+    let source = r#"
+"use runner";
+export async function outer(input) {
+  async function nestedFunction() {}
+  const nestedArrow = async () => {};
+  const fooSchema = z.object({});
+  return nestedArrow(fooSchema.parse(input));
+}
+export const arrow = async () => {
+  const barSchema = z.object({});
+  return async function inner() {};
+};
+"#;
+
+    let metadata = extract_file(source, Path::new("runners/nested.ts"));
+
+    assert_eq!(names(&metadata), (vec!["outer", "arrow"], vec![]));
+}
+
+#[test]
+fn exported_namespace_members_are_module_scope() {
+    let source = r#"
+"use runner";
+export namespace Billing {
+  export const chargeSchema = z.object({});
+  export async function charge() {}
+  async function helper() {}
+}
+namespace Internal {
+  export async function hidden() {}
+}
+"#;
+
+    let metadata = extract_file(source, Path::new("runners/billing.ts"));
+
+    assert_eq!(
+        names(&metadata),
+        (vec!["Billing.charge"], vec!["Billing.chargeSchema"])
+    );
+    assert_eq!(metadata.schemas[0].runner_name.as_deref(), Some("Billing.charge"));
+}
+
+#[test]
+fn runner_factories_are_configurable() {
+    let source = r#"
+"use runner";
+export const inputSchema = z.object({});
+export const a = createRunner(inputSchema, async (ctx, input) => {});
+export const b = defineRunner(inputSchema, async (ctx, input) => {});
+"#;
+    let path = Path::new("runners/factories.ts");
+
+    let metadata = extract_file_with_diagnostics(
+        source,
+        path,
+        &["createRunner".to_string()],
+        DEFAULT_TYPO_THRESHOLD,
+        &mut Vec::new(),
+    );
+
+    assert_eq!(names(&metadata), (vec!["a"], vec!["inputSchema"]));
+    assert_eq!(metadata.runners[0].input_schema.as_deref(), Some("inputSchema"));
+    assert_eq!(metadata.schemas[0].runner_name.as_deref(), Some("a"));
+}
+
+#[test]
+fn commonjs_exports_are_detected() {
+    let source = r#"
+"use runner";
+const { z } = require("zod");
+async function fetchUser(ctx) {}
+exports.fetchUser = fetchUser;
+module.exports.checkTitle = async (ctx) => {};
+module.exports.checkTitleSchema = z.object({});
+exports.helper = (ctx) => {};
+"#;
+
+    let metadata = extract_file(source, Path::new("runners/legacy.cjs"));
+
+    assert_eq!(
+        names(&metadata),
+        (vec!["fetchUser", "checkTitle"], vec!["checkTitleSchema"])
+    );
+    assert_eq!(metadata.schemas[0].runner_name.as_deref(), Some("checkTitle"));
+}
+
+#[test]
+fn reassigning_module_exports_drops_earlier_exports() {
+    let source = r#"
+"use runner";
+exports.a = async (ctx) => {};
+module.exports.aSchema = z.object({});
+module.exports = { first: async (ctx) => {} };
+async function b(ctx) {}
+module.exports = {
+  b,
+  async checkMeta(ctx) {},
+  helper: (ctx) => {},
+};
+module.exports.c = async (ctx) => {};
+exports.d = async (ctx) => {};
+"#;
+
+    let metadata = extract_file(source, Path::new("runners/legacy.cjs"));
+
+    assert_eq!(names(&metadata), (vec!["b", "checkMeta", "c"], vec![]));
+}
+
+#[test]
+fn javascript_files_may_contain_jsx() {
+    let source = r#"
+"use runner";
+export async function widget(ctx) {
+  return <div className="widget">{ctx.name}</div>;
+}
+"#;
+
+    for path in ["runners/widget.js", "runners/widget.mjs", "runners/widget.cjs"] {
+        let mut diagnostics = Vec::new();
+        let metadata = extract_file_with_diagnostics(
+            source,
+            Path::new(path),
+            &[],
+            DEFAULT_TYPO_THRESHOLD,
+            &mut diagnostics,
+        );
+
+        assert!(!metadata.partial, "{path} should parse");
+        assert!(diagnostics.is_empty(), "{path}: {diagnostics:?}");
+        assert_eq!(names(&metadata), (vec!["widget"], vec![]));
+    }
+}
+
+#[test]
+fn typo_threshold_is_configurable() {
+    // Two edits from "use runner"
+    let source = "\"usr rnner\";\nexport async function a(ctx) {}\n";
+    let typos = |threshold| {
+        let mut diagnostics = Vec::new();
+        extract_file_with_diagnostics(source, Path::new("runners/typo.ts"), &[], threshold, &mut diagnostics);
+        diagnostics.len()
+    };
+
+    assert_eq!(typos(2), 1);
+    assert_eq!(typos(1), 0);
+}
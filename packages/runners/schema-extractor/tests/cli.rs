@@ -0,0 +1,133 @@
+use schema_extractor::ENVELOPE_JSON_SCHEMA;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixture").join(name)
+}
+
+/// Run the CLI against the fixture project, writing to a fresh output file.
+fn run(name: &str, args: &[&str]) -> (Output, PathBuf) {
+    run_in(fixture("project"), name, args)
+}
+
+fn run_in(cwd: PathBuf, name: &str, args: &[&str]) -> (Output, PathBuf) {
+    let output_path = std::env::temp_dir().join(format!(
+        "schema-extractor-cli-{}-{}.json",
+        name,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&output_path);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_schema-extractor"))
+        .arg("--cwd")
+        .arg(cwd)
+        .args(["--patterns", "**/*.ts", "--output"])
+        .arg(&output_path)
+        .args(args)
+        .output()
+        .expect("schema-extractor should run");
+    (output, output_path)
+}
+
+fn read_json(path: &Path) -> Value {
+    let json = std::fs::read_to_string(path).expect("output should be written");
+    std::fs::remove_file(path).ok();
+    serde_json::from_str(&json).expect("output should be JSON")
+}
+
+#[test]
+fn writes_versioned_envelope_by_default() {
+    let (output, path) = run("envelope", &[]);
+    assert!(output.status.success());
+
+    let envelope = read_json(&path);
+    assert_eq!(envelope["version"], 2);
+    assert_eq!(envelope["generator"]["name"], "schema_extractor");
+    assert!(Path::new(envelope["root"].as_str().unwrap()).is_absolute());
+    assert_eq!(envelope["diagnostics"], Value::Array(Vec::new()));
+
+    let files = envelope["files"].as_array().unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0]["file"], "runners/fetch.ts");
+    assert_eq!(files[0]["partial"], false);
+    assert_eq!(files[0]["runners"][0]["name"], "fetchPage");
+}
+
+#[test]
+fn format_version_1_writes_legacy_array() {
+    let (output, path) = run("legacy", &["--format-version", "1"]);
+    assert!(output.status.success());
+
+    let expected = serde_json::json!([{
+        "file": "runners/fetch.ts",
+        "runners": [{ "name": "fetchPage", "line": 7 }],
+        "schemas": [{ "name": "fetchPageInputSchema", "runner_name": "fetchPage", "line": 5 }]
+    }]);
+    assert_eq!(read_json(&path), expected);
+}
+
+#[test]
+fn rejects_unknown_format_version() {
+    let (output, path) = run("unknown-version", &["--format-version", "3"]);
+
+    assert!(!output.status.success());
+    assert!(!path.exists());
+}
+
+#[test]
+fn print_schema_prints_envelope_schema() {
+    let (output, path) = run("print-schema", &["--print-schema"]);
+
+    assert!(output.status.success());
+    assert!(!path.exists());
+    let printed = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(printed, ENVELOPE_JSON_SCHEMA);
+
+    let schema: Value = serde_json::from_str(&printed).expect("schema should be JSON");
+    assert_eq!(schema["properties"]["version"]["const"], 2);
+}
+
+#[test]
+fn warnings_fail_only_with_strict() {
+    let (output, path) = run_in(fixture("warnings"), "lenient", &[]);
+    assert!(output.status.success());
+    std::fs::remove_file(path).ok();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(
+        stderr,
+        "runners/orphan.ts:5:1: warning[unmatched-schema]: \
+         Schema \"orphanSchema\" does not match any runner in this file\n"
+    );
+
+    let (output, path) = run_in(fixture("warnings"), "strict", &["--strict"]);
+    assert_eq!(output.status.code(), Some(1));
+    std::fs::remove_file(path).ok();
+}
+
+#[test]
+fn diagnostics_format_selects_the_output() {
+    let (output, path) = run_in(fixture("warnings"), "json", &["--diagnostics-format", "json"]);
+    std::fs::remove_file(path).ok();
+    let diagnostics: Value = serde_json::from_slice(&output.stderr).expect("stderr should be JSON");
+    assert_eq!(diagnostics[0]["code"], "unmatched-schema");
+    assert_eq!(diagnostics[0]["severity"], "warning");
+
+    let (output, path) = run_in(fixture("warnings"), "github", &["--diagnostics-format", "github"]);
+    std::fs::remove_file(path).ok();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(
+        "::warning file=runners/orphan.ts,line=5,col=1,endLine=5,endColumn=1,title=unmatched-schema::"
+    ));
+}
+
+#[test]
+fn default_exports_in_several_files_do_not_fail() {
+    let (output, path) = run_in(fixture("defaults"), "defaults", &[]);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let envelope = read_json(&path);
+    assert_eq!(envelope["files"].as_array().unwrap().len(), 2);
+    assert_eq!(envelope["diagnostics"], Value::Array(Vec::new()));
+}
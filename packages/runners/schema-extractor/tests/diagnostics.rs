@@ -0,0 +1,176 @@
+use schema_extractor::diagnostics::{
+    check_duplicate_runners, check_unmatched_schemas, format_github, format_human, should_fail,
+};
+use schema_extractor::{
+    Diagnostic, DiagnosticCode, Position, Range, RunnerInfo, SchemaInfo, SchemaMetadata, Severity,
+};
+
+fn diagnostic(severity: Severity, range: Option<Range>, message: &str) -> Diagnostic {
+    Diagnostic::new(
+        "runners/check.ts",
+        range,
+        severity,
+        DiagnosticCode::ParseError,
+        message,
+    )
+}
+
+fn range() -> Range {
+    Range {
+        start: Position { line: 3, column: 5 },
+        end: Position { line: 4, column: 2 },
+    }
+}
+
+fn metadata(file: &str, runners: &[&str], schemas: &[(&str, Option<&str>)]) -> SchemaMetadata {
+    SchemaMetadata {
+        file: file.to_string(),
+        runners: runners
+            .iter()
+            .enumerate()
+            .map(|(i, name)| RunnerInfo {
+                name: name.to_string(),
+                line: i + 1,
+                input_schema: None,
+            })
+            .collect(),
+        schemas: schemas
+            .iter()
+            .map(|(name, runner_name)| SchemaInfo {
+                name: name.to_string(),
+                runner_name: runner_name.map(str::to_string),
+                line: 10,
+            })
+            .collect(),
+        partial: false,
+    }
+}
+
+#[test]
+fn human_format_has_location_severity_and_code() {
+    let with_range = diagnostic(Severity::Error, Some(range()), "Syntax error");
+    let without_range = diagnostic(Severity::Warning, None, "Could not read");
+
+    assert_eq!(
+        format_human(&with_range),
+        "runners/check.ts:3:5: error[parse-error]: Syntax error"
+    );
+    assert_eq!(
+        format_human(&without_range),
+        "runners/check.ts: warning[parse-error]: Could not read"
+    );
+}
+
+#[test]
+fn github_format_is_a_workflow_command() {
+    let with_range = diagnostic(Severity::Error, Some(range()), "Syntax error");
+    let without_range = diagnostic(Severity::Warning, None, "Could not read");
+
+    assert_eq!(
+        format_github(&with_range),
+        "::error file=runners/check.ts,line=3,col=5,endLine=4,endColumn=2,title=parse-error::Syntax error"
+    );
+    assert_eq!(
+        format_github(&without_range),
+        "::warning file=runners/check.ts,title=parse-error::Could not read"
+    );
+}
+
+#[test]
+fn github_format_escapes_data_and_properties() {
+    let diagnostic = Diagnostic::new(
+        "runners/a,b:c%.ts",
+        None,
+        Severity::Error,
+        DiagnosticCode::ParseError,
+        "100% broken\r\nsecond line",
+    );
+
+    assert_eq!(
+        format_github(&diagnostic),
+        "::error file=runners/a%2Cb%3Ac%25.ts,title=parse-error::100%25 broken%0D%0Asecond line"
+    );
+}
+
+#[test]
+fn json_format_serializes_codes_and_severities() {
+    let json = serde_json::to_value([diagnostic(Severity::Warning, Some(range()), "Syntax error")])
+        .unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "file": "runners/check.ts",
+            "range": {
+                "start": { "line": 3, "column": 5 },
+                "end": { "line": 4, "column": 2 }
+            },
+            "severity": "warning",
+            "code": "parse-error",
+            "message": "Syntax error"
+        }])
+    );
+}
+
+#[test]
+fn errors_always_fail_and_warnings_only_when_strict() {
+    let errors = [diagnostic(Severity::Error, None, "error")];
+    let warnings = [diagnostic(Severity::Warning, None, "warning")];
+
+    assert!(should_fail(&errors, false));
+    assert!(should_fail(&errors, true));
+    assert!(!should_fail(&warnings, false));
+    assert!(should_fail(&warnings, true));
+    assert!(!should_fail(&[], true));
+}
+
+#[test]
+fn unmatched_schemas_are_warnings() {
+    let metadata = metadata(
+        "runners/check.ts",
+        &["checkTitle"],
+        &[("checkTitleSchema", Some("checkTitle")), ("orphanSchema", None)],
+    );
+    let mut diagnostics = Vec::new();
+
+    check_unmatched_schemas(&metadata, &mut diagnostics);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::UnmatchedSchema);
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(diagnostics[0].range, Some(Range::line(10)));
+    assert!(diagnostics[0].message.contains("orphanSchema"));
+}
+
+#[test]
+fn duplicate_runners_are_errors() {
+    let files = [
+        metadata("runners/a.ts", &["checkTitle"], &[]),
+        metadata("runners/b.ts", &["checkLinks", "checkTitle"], &[]),
+    ];
+    let mut diagnostics = Vec::new();
+
+    check_duplicate_runners(&files, &mut diagnostics);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::DuplicateRunner);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].file, "runners/b.ts");
+    assert_eq!(
+        diagnostics[0].message,
+        "Runner \"checkTitle\" is already defined at runners/a.ts:1"
+    );
+}
+
+#[test]
+fn default_exports_are_not_duplicates() {
+    let files = [
+        metadata("runners/a.ts", &["default"], &[]),
+        metadata("runners/b.ts", &["default"], &[]),
+    ];
+    let mut diagnostics = Vec::new();
+
+    check_duplicate_runners(&files, &mut diagnostics);
+
+    assert!(diagnostics.is_empty());
+}
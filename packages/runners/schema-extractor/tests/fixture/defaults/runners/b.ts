@@ -0,0 +1,3 @@
+"use runner";
+
+export default async (ctx) => ({ name: "b", status: "pass" });
@@ -0,0 +1,3 @@
+export async function notARunner() {
+  return 1;
+}
@@ -0,0 +1,9 @@
+"use runner";
+
+import { z } from "zod";
+
+export const orphanSchema = z.object({});
+
+export async function checkTitle(ctx) {
+  return { name: "checkTitle", status: "pass" };
+}
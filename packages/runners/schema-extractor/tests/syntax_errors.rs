@@ -0,0 +1,106 @@
+use schema_extractor::{
+    extract_file_with_diagnostics, Diagnostic, DiagnosticCode, Position, Range, SchemaMetadata,
+    Severity, DEFAULT_TYPO_THRESHOLD,
+};
+use std::path::Path;
+
+fn extract(source: &str) -> (SchemaMetadata, Vec<Diagnostic>) {
+    let mut diagnostics = Vec::new();
+    let metadata = extract_file_with_diagnostics(
+        source,
+        Path::new("runners/broken.ts"),
+        &[],
+        DEFAULT_TYPO_THRESHOLD,
+        &mut diagnostics,
+    );
+    (metadata, diagnostics)
+}
+
+fn range((start_line, start_column): (usize, usize), (end_line, end_column): (usize, usize)) -> Range {
+    Range {
+        start: Position {
+            line: start_line,
+            column: start_column,
+        },
+        end: Position {
+            line: end_line,
+            column: end_column,
+        },
+    }
+}
+
+#[test]
+fn error_nodes_are_reported_with_their_range() {
+    let source = r#""use runner";
+export async function a(ctx) {
+  const x = ;
+}
+export async function b(ctx) {}
+"#;
+
+    let (metadata, diagnostics) = extract(source);
+
+    assert!(metadata.partial);
+    assert_eq!(metadata.runners.len(), 2);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::ParseError);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(diagnostics[0].range, Some(range((3, 11), (3, 12))));
+    assert_eq!(
+        diagnostics[0].message,
+        "Syntax error; extracted metadata may be incomplete"
+    );
+}
+
+#[test]
+fn missing_nodes_name_the_missing_token() {
+    let source = r#""use runner";
+const x = 1
+export async function a(ctx) { if (x { } }
+"#;
+
+    let (metadata, diagnostics) = extract(source);
+
+    assert!(metadata.partial);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].range, Some(range((3, 37), (3, 37))));
+    assert_eq!(
+        diagnostics[0].message,
+        "Missing \")\"; extracted metadata may be incomplete"
+    );
+}
+
+#[test]
+fn each_error_is_reported_once() {
+    let source = r#""use runner";
+export async function a(ctx) {
+  const x = ;
+}
+export async function b(ctx) { if (x { } }
+"#;
+
+    let (_, diagnostics) = extract(source);
+
+    let ranges: Vec<_> = diagnostics.iter().map(|d| d.range).collect();
+    assert_eq!(
+        ranges,
+        [Some(range((3, 11), (3, 12))), Some(range((5, 37), (5, 37)))]
+    );
+}
+
+#[test]
+fn columns_count_characters() {
+    let source = "\"use runner\";\nexport async function a(ctx) {\n  const é = ;\n}\n";
+
+    let (_, diagnostics) = extract(source);
+
+    assert_eq!(diagnostics[0].range, Some(range((3, 11), (3, 12))));
+}
+
+#[test]
+fn clean_files_are_not_partial() {
+    let (metadata, diagnostics) = extract("\"use runner\";\nexport async function a(ctx) {}\n");
+
+    assert!(!metadata.partial);
+    assert!(diagnostics.is_empty());
+}
@@ -0,0 +1,123 @@
+//! Import stripping for client mode.
+//!
+//! In client mode [`crate::rewrite`] replaces runner bodies with stubs that
+//! call the runner over HTTP, so whatever only the bodies used is dead code.
+//! Removing those imports keeps Playwright and other runner dependencies out
+//! of client bundles; imports still used elsewhere in the file, such as the
+//! schema library, and side-effect imports are kept.
+
+use std::collections::HashSet;
+use swc_core::ecma::{
+    ast::*,
+    visit::{Visit, VisitWith},
+};
+
+/// `require("module")`
+fn is_require_call(expr: Option<&Expr>) -> bool {
+    let Some(Expr::Call(call)) = expr else {
+        return false;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    let is_require = matches!(callee.as_ref(), Expr::Ident(ident) if &*ident.sym == "require");
+    let has_module_arg = matches!(
+        call.args.as_slice(),
+        [arg] if arg.spread.is_none() && matches!(arg.expr.as_ref(), Expr::Lit(Lit::Str(_)))
+    );
+    is_require && has_module_arg
+}
+
+/// Names bound by `const name = ...` or `const { a, b: c } = ...`, or `None`
+/// for patterns too involved to tell.
+fn bound_names(pat: &Pat) -> Option<Vec<String>> {
+    match pat {
+        Pat::Ident(binding) => Some(vec![binding.id.sym.to_string()]),
+        Pat::Object(object) => object
+            .props
+            .iter()
+            .map(|prop| match prop {
+                ObjectPatProp::Assign(AssignPatProp { key, .. }) => Some(key.sym.to_string()),
+                ObjectPatProp::KeyValue(KeyValuePatProp { value, .. }) => match value.as_ref() {
+                    Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Every identifier in the file outside imports and `require` declarations.
+/// Declarations count too, so a name is only ever reported unused when it
+/// really is.
+#[derive(Default)]
+struct UsedNames(HashSet<String>);
+
+impl Visit for UsedNames {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.0.insert(ident.sym.to_string());
+    }
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_var_declarator(&mut self, declarator: &VarDeclarator) {
+        if !is_require_call(declarator.init.as_deref()) {
+            declarator.visit_children_with(self);
+        }
+    }
+}
+
+fn used_names<T: VisitWith<UsedNames>>(items: &[T]) -> HashSet<String> {
+    let mut used = UsedNames::default();
+    for item in items {
+        item.visit_with(&mut used);
+    }
+    used.0
+}
+
+/// Remove unused import specifiers from a module, and imports left without
+/// any.
+pub(crate) fn strip_unused_imports(items: &mut Vec<ModuleItem>) {
+    let used = used_names(items);
+
+    items.retain_mut(|item| {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            return true;
+        };
+        // `import "./setup"` is only there for its side effects
+        if import.specifiers.is_empty() {
+            return true;
+        }
+
+        import.specifiers.retain(|specifier| {
+            let local = match specifier {
+                ImportSpecifier::Named(named) => &named.local,
+                ImportSpecifier::Default(default) => &default.local,
+                ImportSpecifier::Namespace(namespace) => &namespace.local,
+            };
+            used.contains(&*local.sym)
+        });
+        !import.specifiers.is_empty()
+    });
+}
+
+/// Remove top-level `const name = require("module")` declarations whose names
+/// are unused from a CommonJS script.
+pub(crate) fn strip_unused_requires(stmts: &mut Vec<Stmt>) {
+    let used = used_names(stmts);
+
+    stmts.retain_mut(|stmt| {
+        let Stmt::Decl(Decl::Var(var)) = stmt else {
+            return true;
+        };
+
+        var.decls.retain(|declarator| {
+            !is_require_call(declarator.init.as_deref())
+                || bound_names(&declarator.name)
+                    .is_none_or(|names| names.iter().any(|name| used.contains(name)))
+        });
+        !var.decls.is_empty()
+    });
+}
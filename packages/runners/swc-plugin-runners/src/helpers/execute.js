@@ -0,0 +1,43 @@
+function __runners_execute(endpoint, name) {
+  return async (ctx, input) => {
+    let url;
+    try {
+      // Relative URLs resolve against the page; elsewhere they must be absolute
+      url = new URL(endpoint, globalThis.location?.href);
+    } catch {
+      return {
+        name,
+        status: "error",
+        errorMessage: `executeUrl must be an absolute URL outside a browser, got "${endpoint}"`,
+      };
+    }
+    try {
+      const response = await fetch(url, {
+        method: "POST",
+        headers: { "content-type": "application/json" },
+        body: JSON.stringify({ runners: [{ name, input }] }),
+      });
+      if (!response.ok) {
+        return {
+          name,
+          status: "error",
+          errorMessage: `Runner request failed with status ${response.status}`,
+        };
+      }
+      const { results } = await response.json();
+      return (
+        results?.[0] ?? {
+          name,
+          status: "error",
+          errorMessage: "Runner response contained no result",
+        }
+      );
+    } catch (error) {
+      return {
+        name,
+        status: "error",
+        errorMessage: error instanceof Error ? error.message : String(error),
+      };
+    }
+  };
+}
@@ -0,0 +1,24 @@
+function __runners_normalizeResult(runnerId, run) {
+  return async (...args) => {
+    const start = Date.now();
+    let result;
+    try {
+      result = await run(...args);
+    } catch (error) {
+      result = {
+        status: "error",
+        errorMessage: error instanceof Error ? error.message : String(error),
+      };
+    }
+    // A runner returning a plain value passed; the value is kept as details
+    if (typeof result !== "object" || result === null) {
+      result = { status: "pass", details: { output: result } };
+    }
+    return {
+      ...result,
+      name: result.name ?? runnerId,
+      // A runner that times itself knows best what to count
+      durationMs: result.durationMs ?? Date.now() - start,
+    };
+  };
+}
@@ -0,0 +1,21 @@
+function __runners_validateInput(runnerId, schema, run) {
+  return async (ctx, input, ...rest) => {
+    if (typeof schema?.["~standard"]?.validate !== "function") {
+      return {
+        name: runnerId,
+        status: "error",
+        errorMessage: "Input schema is not a Standard Schema: it has no ~standard.validate function",
+      };
+    }
+    const result = await schema["~standard"].validate(input);
+    if (result.issues) {
+      const messages = result.issues.map((issue) => issue.message).join(", ");
+      return {
+        name: runnerId,
+        status: "error",
+        errorMessage: `Validation failed: ${messages}`,
+      };
+    }
+    return run(ctx, result.value, ...rest);
+  };
+}
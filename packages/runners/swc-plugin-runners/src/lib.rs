@@ -1,9 +1,28 @@
 #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-use serde::Deserialize;
-use std::path::Path;
+mod client;
+mod location;
+mod metadata;
+mod prologue;
+mod registry;
+mod rewrite;
+
+pub use metadata::{collect_metadata, collect_script_metadata};
+pub use runners_model::{RunnerInfo, SchemaInfo, SchemaMetadata};
+
+use metadata::{scan_module, scan_script, FileScan};
+use prologue::{as_stmt, DirectivePrologue, PrologueAnalysis};
+use rewrite::{str_lit, RunnerRewriter};
+
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, path::Path};
 use swc_core::{
-    common::errors::HANDLER,
+    common::{
+        comments::{Comment, CommentKind, Comments},
+        errors::{DiagnosticId, SourceMapperDyn, HANDLER},
+        sync::Lrc,
+        BytePos, DUMMY_SP,
+    },
     ecma::{
         ast::*,
         visit::{VisitMut, VisitMutWith},
@@ -11,13 +30,129 @@ use swc_core::{
     plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
 };
 
-#[derive(Deserialize)]
+/// Plugin options, passed as the second element of the SWC plugin tuple.
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-#[allow(dead_code)]
-struct WasmConfig {
-    // No config needed for now, but keeping structure for future extensibility
+pub struct WasmConfig {
+    /// Collect runner and schema metadata (the same data `schema_extractor`
+    /// produces) and emit it in the `__internal_runners` manifest comment.
+    #[serde(default)]
+    pub extract_metadata: bool,
+    /// Maximum edit distance at which a directive is reported as a
+    /// misspelled `"use runner"`; 0 only catches case, whitespace and hyphen
+    /// variants such as `"Use-Runner"`.
+    #[serde(default = "default_typo_threshold")]
+    pub typo_threshold: usize,
+    /// Calls whose last argument is a runner function, such as
+    /// `defineRunner(inputSchema, async (ctx, input) => {})`. Only used for
+    /// metadata.
+    #[serde(default = "default_runner_factories")]
+    pub runner_factories: Vec<String>,
+    /// Validate a runner's `input` against its Standard Schema before the
+    /// body runs. The schema is the one named by a `Runner<typeof Schema>`
+    /// annotation or a module-scope `<name>InputSchema` variable; runners
+    /// without either are left alone.
+    #[serde(default)]
+    pub validate_input: bool,
+    /// Time every runner into `durationMs`, turn what it throws into a
+    /// `status: "error"` result and default a missing `name` to the runner ID,
+    /// so every harness gets the same result from the compiled code.
+    #[serde(default)]
+    pub normalize_results: bool,
+    /// `client` replaces runner bodies with stubs that call the runner over
+    /// HTTP and strips the imports only they used.
+    #[serde(default)]
+    pub mode: TransformMode,
+    /// Where client stubs POST `{ runner, input }`; used in client mode.
+    #[serde(default = "default_execute_url")]
+    pub execute_url: String,
+    /// Append `export const __runners = { [runnerId]: { fn, name, file, line,
+    /// inputSchema } }` to files with runners, so handlers can build their
+    /// registry from imports alone.
+    #[serde(default)]
+    pub register_runners: bool,
+    /// Attach `{ file, line, column, runnerId }` to every runner function as
+    /// its non-enumerable `__runnerLocation` property, for error attribution
+    /// that survives bundling.
+    #[serde(default)]
+    pub source_locations: bool,
+}
+
+/// What the transform builds runner modules for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransformMode {
+    /// Runners run where they are imported
+    #[default]
+    Server,
+    /// Runners are called remotely, through the runner HTTP API
+    Client,
+}
+
+fn default_typo_threshold() -> usize {
+    runners_model::DEFAULT_TYPO_THRESHOLD
+}
+
+fn default_execute_url() -> String {
+    "/api/runner/execute".to_string()
+}
+
+fn default_runner_factories() -> Vec<String> {
+    runners_model::DEFAULT_RUNNER_FACTORIES
+        .iter()
+        .map(|f| f.to_string())
+        .collect()
+}
+
+impl Default for WasmConfig {
+    fn default() -> Self {
+        Self {
+            extract_metadata: false,
+            typo_threshold: default_typo_threshold(),
+            runner_factories: default_runner_factories(),
+            validate_input: false,
+            normalize_results: false,
+            mode: TransformMode::default(),
+            execute_url: default_execute_url(),
+            register_runners: false,
+            source_locations: false,
+        }
+    }
 }
 
+impl WasmConfig {
+    /// Whether the file has to be scanned for runners and schemas.
+    fn needs_scan(&self) -> bool {
+        self.extract_metadata
+            || self.validate_input
+            || self.normalize_results
+            || self.mode == TransformMode::Client
+            || self.register_runners
+            || self.source_locations
+    }
+}
+
+/// Payload of the `/**__internal_runners{...}*/` comment read by `@runners/builders`.
+#[derive(Serialize)]
+struct RunnerManifest<'a> {
+    /// Runner IDs keyed by file, then export name
+    runners: BTreeMap<&'a str, BTreeMap<&'a str, ManifestRunner>>,
+    metadata: &'a SchemaMetadata,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ManifestRunner {
+    runner_id: String,
+}
+
+const MANIFEST_COMMENT_PREFIX: &str = "*__internal_runners";
+
+/// Marks output written without a manifest: the text of a
+/// `/*__runners_transformed*/` comment, or of a `"__runners_transformed"`
+/// directive when the transform has no comments to write to.
+const TRANSFORMED_MARKER: &str = "__runners_transformed";
+
 #[derive(Debug, Clone)]
 enum RunnerErrorKind {
     NonAsyncFunction {
@@ -26,6 +161,7 @@ enum RunnerErrorKind {
     MisplacedDirective {
         span: swc_core::common::Span,
         location: DirectiveLocation,
+        directive: String,
     },
     MisspelledDirective {
         span: swc_core::common::Span,
@@ -39,22 +175,43 @@ enum DirectiveLocation {
     FunctionBody,
 }
 
+impl RunnerErrorKind {
+    /// Stable code shown with the error; matches the schema extractor's
+    /// diagnostic codes where both tools report the same problem.
+    fn code(&self) -> &'static str {
+        match self {
+            RunnerErrorKind::NonAsyncFunction { .. } => "non-async-function",
+            RunnerErrorKind::MisplacedDirective { .. } => "misplaced-directive",
+            RunnerErrorKind::MisspelledDirective { .. } => "misspelled-directive",
+        }
+    }
+}
+
 fn emit_error(error: RunnerErrorKind) {
+    let code = DiagnosticId::Error(error.code().to_string());
     let (span, msg) = match error {
         RunnerErrorKind::NonAsyncFunction { span } => (
             span,
             "Functions marked with \"use runner\" must be async functions".to_string(),
         ),
-        RunnerErrorKind::MisplacedDirective { span, location } => (
+        RunnerErrorKind::MisplacedDirective {
             span,
-            format!(
-                "The \"use runner\" directive must be at the top of the {}",
-                match location {
-                    DirectiveLocation::Module => "file",
-                    DirectiveLocation::FunctionBody => "function body",
-                }
-            ),
-        ),
+            location,
+            directive,
+        } => {
+            let location = match location {
+                DirectiveLocation::Module => "file",
+                DirectiveLocation::FunctionBody => "function body",
+            };
+            let msg = if runners_model::is_use_runner_directive(&directive) {
+                format!("The \"use runner\" directive must be at the top of the {location}")
+            } else {
+                format!(
+                    "\"{directive}\" looks like a misplaced \"use runner\" directive; directives must be at the top of the {location}"
+                )
+            };
+            (span, msg)
+        }
         RunnerErrorKind::MisspelledDirective { span, directive } => (
             span,
             format!(
@@ -64,44 +221,7 @@ fn emit_error(error: RunnerErrorKind) {
         ),
     };
 
-    HANDLER.with(|handler| handler.struct_span_err(span, &msg).emit());
-}
-
-// Helper function to detect similar strings (typos)
-fn detect_similar_strings(a: &str, b: &str) -> bool {
-    let a_chars: Vec<char> = a.chars().collect();
-    let b_chars: Vec<char> = b.chars().collect();
-
-    if (a_chars.len() as i32 - b_chars.len() as i32).abs() > 1 {
-        return false;
-    }
-
-    let mut differences = 0;
-    let mut i = 0;
-    let mut j = 0;
-
-    while i < a_chars.len() && j < b_chars.len() {
-        if a_chars[i] != b_chars[j] {
-            differences += 1;
-            if differences > 1 {
-                return false;
-            }
-
-            if a_chars.len() > b_chars.len() {
-                i += 1;
-            } else if b_chars.len() > a_chars.len() {
-                j += 1;
-            } else {
-                i += 1;
-                j += 1;
-            }
-        } else {
-            i += 1;
-            j += 1;
-        }
-    }
-
-    differences + (a_chars.len() - i) + (b_chars.len() - j) == 1
+    HANDLER.with(|handler| handler.struct_span_err_with_code(span, &msg, code).emit());
 }
 
 pub struct RunnerTransform {
@@ -109,9 +229,17 @@ pub struct RunnerTransform {
     has_module_directive: bool,
     // Track if we're currently processing a function with a directive
     in_function_with_directive: bool,
-    // Filename for better error messages (stored for potential future use)
-    #[allow(dead_code)]
+    // Whether any "use runner" directive was found in the file
+    found_directive: bool,
+    // Whether a misspelled or misplaced directive was reported in the file
+    reported_directive: bool,
+    // Relative, forward-slash filename used in errors and metadata
     filename: String,
+    config: WasmConfig,
+    // Where the manifest comment is written; metadata is skipped without it
+    comments: Option<Box<dyn Comments>>,
+    // Used to resolve line numbers for metadata; lines are 0 without it
+    source_map: Option<Lrc<SourceMapperDyn>>,
 }
 
 impl RunnerTransform {
@@ -119,107 +247,312 @@ impl RunnerTransform {
         Self {
             has_module_directive: false,
             in_function_with_directive: false,
+            found_directive: false,
+            reported_directive: false,
             filename,
+            config: WasmConfig::default(),
+            comments: None,
+            source_map: None,
         }
     }
 
-    fn is_use_runner_directive(directive: &Str) -> bool {
-        directive.value == "use runner"
+    pub fn with_config(mut self, config: WasmConfig) -> Self {
+        self.config = config;
+        self
     }
 
-    fn check_directive_typo(directive: &Str) -> bool {
-        detect_similar_strings(&directive.value, "use runner")
+    pub fn with_comments(mut self, comments: impl Comments + 'static) -> Self {
+        self.comments = Some(Box::new(comments));
+        self
     }
 
-    fn remove_directive_from_stmts(stmts: &mut Vec<Stmt>) {
-        stmts.retain(|stmt| {
-            if let Stmt::Expr(ExprStmt { expr, .. }) = stmt {
-                if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                    // Remove valid "use runner" directives
-                    if Self::is_use_runner_directive(str_lit) {
-                        return false;
-                    }
-                    // Remove misspelled directives
-                    if Self::check_directive_typo(str_lit) {
-                        return false;
-                    }
-                }
+    pub fn with_source_map(mut self, source_map: Lrc<SourceMapperDyn>) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    /// Prepend the manifest comment carrying this file's runner metadata to
+    /// the program starting at `pos`.
+    fn emit_metadata(&self, pos: BytePos, metadata: &SchemaMetadata) {
+        let Some(comments) = &self.comments else {
+            return;
+        };
+
+        let runners = metadata
+            .runners
+            .iter()
+            .map(|runner| {
+                let runner_id = runners_model::runner_id(&metadata.file, &runner.name);
+                (runner.name.as_str(), ManifestRunner { runner_id })
+            })
+            .collect();
+        let json = serde_json::to_string(&RunnerManifest {
+            runners: BTreeMap::from([(metadata.file.as_str(), runners)]),
+            metadata,
+        })
+        .expect("runner manifest should serialize")
+        // Keep names containing "*/" from closing the comment early
+        .replace("*/", "*\\/");
+
+        comments.add_leading(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: format!("{MANIFEST_COMMENT_PREFIX}{json}").into(),
+            },
+        );
+    }
+
+    /// Mark the program starting at `pos` with `body` as transformed, so a
+    /// later run recognizes it even when no manifest was written. Without
+    /// comments the marker is a directive, which survives printing as code.
+    fn mark_transformed<T: From<Stmt>>(&self, pos: BytePos, body: &mut Vec<T>) {
+        let Some(comments) = &self.comments else {
+            let marker = Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: str_lit(TRANSFORMED_MARKER),
+            });
+            body.insert(0, T::from(marker));
+            return;
+        };
+
+        comments.add_leading(
+            pos,
+            Comment {
+                kind: CommentKind::Block,
+                span: DUMMY_SP,
+                text: TRANSFORMED_MARKER.into(),
+            },
+        );
+    }
+
+    /// Write the manifest, or else the transformed marker, to a program
+    /// starting at `pos` that this run found or reported directives in.
+    fn finish_program<T: From<Stmt>>(
+        &self,
+        pos: BytePos,
+        body: &mut Vec<T>,
+        metadata: Option<&SchemaMetadata>,
+    ) {
+        match metadata {
+            // The manifest is a comment, so it needs somewhere to go
+            Some(metadata) if self.config.extract_metadata && self.comments.is_some() => {
+                self.emit_metadata(pos, metadata)
             }
-            true
+            _ if self.found_directive || self.reported_directive => {
+                self.mark_transformed(pos, body)
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether the program starting at `pos` with `body` already carries the
+    /// manifest comment or the transformed marker, i.e. it is the output of
+    /// an earlier run of this transform.
+    ///
+    /// Such a program is left alone: its directives are gone, and running
+    /// again would only duplicate the manifest and report the same problems
+    /// twice, including misplaced directives, which stay in the output.
+    fn is_transformed<T>(
+        &self,
+        pos: BytePos,
+        body: &[T],
+        as_stmt: fn(&T) -> Option<&Stmt>,
+    ) -> bool {
+        let has_marker_comment = self.comments.as_ref().is_some_and(|comments| {
+            comments.get_leading(pos).is_some_and(|leading| {
+                leading.iter().any(|comment| {
+                    comment.text.starts_with(MANIFEST_COMMENT_PREFIX)
+                        || comment.text == TRANSFORMED_MARKER
+                })
+            })
         });
+        has_marker_comment
+            || DirectivePrologue::of(body, as_stmt)
+                .directives
+                .iter()
+                .any(|directive| &*directive.str_lit.value == TRANSFORMED_MARKER)
+    }
+
+    /// Analyze the directive prologue of a module or function body in one
+    /// pass, report typos and misplaced directives, and strip `"use runner"`
+    /// and its typos from the prologue. Returns the span of the directive if
+    /// the prologue has one.
+    ///
+    /// The directive may appear anywhere in the directive prologue, alongside
+    /// others like `"use strict"`. Typos are only reported as such inside the
+    /// prologue; anything that looks like the directive further down is
+    /// ordinary code, so it is reported as misplaced and left in place.
+    fn process_prologue<T>(
+        &mut self,
+        items: &mut Vec<T>,
+        as_stmt: fn(&T) -> Option<&Stmt>,
+        location: DirectiveLocation,
+    ) -> Option<swc_core::common::Span> {
+        let analysis = PrologueAnalysis::of(items, as_stmt, |value| {
+            runners_model::is_directive_typo(value, self.config.typo_threshold)
+        });
+
+        if !analysis.typos.is_empty() || !analysis.misplaced.is_empty() {
+            self.reported_directive = true;
+        }
+        for typo in &analysis.typos {
+            emit_error(RunnerErrorKind::MisspelledDirective {
+                span: typo.span,
+                directive: typo.value.clone(),
+            });
+        }
+        for misplaced in &analysis.misplaced {
+            emit_error(RunnerErrorKind::MisplacedDirective {
+                span: misplaced.span,
+                location: location.clone(),
+                directive: misplaced.value.clone(),
+            });
+        }
+
+        analysis.remove_directives(items);
+        analysis.runner_directive
+    }
+
+    /// Rewrite the runners found by `scan` in a module or script body for
+    /// the enabled runtime modes, and append the helpers they call.
+    fn rewrite_runners<T: From<Stmt>>(&self, body: &mut Vec<T>, scan: &FileScan)
+    where
+        Vec<T>: VisitMutWith<RunnerRewriter>,
+    {
+        let mut rewriter = RunnerRewriter::new(scan, &self.config);
+        body.visit_mut_with(&mut rewriter);
+        body.extend(rewriter.helper_declarations().into_iter().map(T::from));
+    }
+
+    /// Check and strip directives in a body that can never be async
+    /// (constructors, getters and setters).
+    fn visit_sync_body(&mut self, body: &mut BlockStmt) {
+        if let Some(span) =
+            self.process_prologue(&mut body.stmts, as_stmt, DirectiveLocation::FunctionBody)
+        {
+            emit_error(RunnerErrorKind::NonAsyncFunction { span });
+        }
     }
 }
 
 impl VisitMut for RunnerTransform {
-    fn visit_mut_module(&mut self, module: &mut Module) {
-        // Check for module-level directive - must be first
-        let mut found_directive = false;
-        let mut directive_span = None;
-        let mut non_directive_before = false;
-
-        for (index, item) in module.body.iter().enumerate() {
-            if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, span })) = item {
-                if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                    if Self::is_use_runner_directive(str_lit) {
-                        found_directive = true;
-                        directive_span = Some(*span);
-                        // Check if it's not the first item
-                        if index > 0 {
-                            non_directive_before = true;
-                        }
-                        break;
-                    } else if Self::check_directive_typo(str_lit) {
-                        // Found a typo
-                        emit_error(RunnerErrorKind::MisspelledDirective {
-                            span: *span,
-                            directive: str_lit.value.to_string(),
-                        });
-                        // Remove the misspelled directive (will be handled in module.body.retain below)
-                    }
-                } else {
-                    // Found non-directive statement before checking for directive
-                    if !found_directive {
-                        non_directive_before = true;
-                    }
-                }
-            } else if !found_directive {
-                // Found non-directive item before directive
-                non_directive_before = true;
+    /// A `.cjs` file is a CommonJS script whatever it was parsed as; tools
+    /// like `@swc/core` parse every file as a module unless told otherwise.
+    fn visit_mut_program(&mut self, program: &mut Program) {
+        if let Program::Module(module) = program {
+            if self.filename.ends_with(".cjs") && module.body.iter().all(ModuleItem::is_stmt) {
+                let module = std::mem::take(module);
+                *program = Program::Script(Script {
+                    span: module.span,
+                    body: module.body.into_iter().filter_map(ModuleItem::stmt).collect(),
+                    shebang: module.shebang,
+                });
             }
         }
 
-        // Check for misplaced directive
-        if found_directive && non_directive_before {
-            if let Some(span) = directive_span {
-                emit_error(RunnerErrorKind::MisplacedDirective {
-                    span,
-                    location: DirectiveLocation::Module,
-                });
-            }
+        program.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        if self.is_transformed(module.span.lo, &module.body, ModuleItem::as_stmt) {
+            return;
         }
 
+        // Collected before directives are stripped, since they decide what is a runner
+        let scan = self.config.needs_scan().then(|| {
+            scan_module(
+                module,
+                &self.filename,
+                &self.config.runner_factories,
+                self.source_map.as_ref(),
+            )
+        });
+
+        // The module-level directive must be in the prologue
+        let found_directive = self
+            .process_prologue(&mut module.body, ModuleItem::as_stmt, DirectiveLocation::Module)
+            .is_some();
+
         if found_directive {
             self.has_module_directive = true;
+            self.found_directive = true;
         }
 
         module.visit_mut_children_with(self);
 
-        // Remove module-level directive and misspelled directives
-        module.body.retain(|item| {
-            if let ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) = item {
-                if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                    // Remove valid "use runner" directives
-                    if Self::is_use_runner_directive(str_lit) {
-                        return false;
-                    }
-                    // Remove misspelled directives
-                    if Self::check_directive_typo(str_lit) {
-                        return false;
-                    }
-                }
+        let scan = scan.filter(|_| self.found_directive);
+        if let Some(scan) = &scan {
+            self.rewrite_runners(&mut module.body, scan);
+            if self.config.source_locations {
+                let default_reference = registry::default_reference(&mut module.body, scan);
+                let locations =
+                    location::location_stmts(scan, default_reference, self.source_map.as_ref());
+                module.body.extend(locations.into_iter().map(ModuleItem::Stmt));
             }
-            true
+            if self.config.register_runners {
+                registry::append_module_registry(&mut module.body, scan);
+            }
+            // After everything that may add references
+            if self.config.mode == TransformMode::Client {
+                client::strip_unused_imports(&mut module.body);
+            }
+        }
+        self.finish_program(
+            module.span.lo,
+            &mut module.body,
+            scan.as_ref().map(|scan| &scan.metadata),
+        );
+    }
+
+    /// CommonJS files are parsed as scripts and get the same prologue
+    /// handling as modules.
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        if self.is_transformed(script.span.lo, &script.body, as_stmt) {
+            return;
+        }
+
+        let scan = self.config.needs_scan().then(|| {
+            scan_script(
+                script,
+                &self.filename,
+                &self.config.runner_factories,
+                self.source_map.as_ref(),
+            )
         });
+
+        let found_directive = self
+            .process_prologue(&mut script.body, as_stmt, DirectiveLocation::Module)
+            .is_some();
+
+        if found_directive {
+            self.has_module_directive = true;
+            self.found_directive = true;
+        }
+
+        script.visit_mut_children_with(self);
+
+        let scan = scan.filter(|_| self.found_directive);
+        if let Some(scan) = &scan {
+            self.rewrite_runners(&mut script.body, scan);
+            if self.config.source_locations {
+                let locations = location::location_stmts(scan, None, self.source_map.as_ref());
+                script.body.extend(locations);
+            }
+            if self.config.register_runners {
+                registry::append_script_registry(&mut script.body, scan);
+            }
+            // After everything that may add references
+            if self.config.mode == TransformMode::Client {
+                client::strip_unused_requires(&mut script.body);
+            }
+        }
+        self.finish_program(
+            script.span.lo,
+            &mut script.body,
+            scan.as_ref().map(|scan| &scan.metadata),
+        );
     }
 
     fn visit_mut_function(&mut self, func: &mut Function) {
@@ -228,54 +561,18 @@ impl VisitMut for RunnerTransform {
 
         // Check for directive at the start of function body
         if let Some(body) = &mut func.body {
-            // Check if first statement is a directive
-            if let Some(first_stmt) = body.stmts.first() {
-                if let Stmt::Expr(ExprStmt { expr, span }) = first_stmt {
-                    if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                        if Self::is_use_runner_directive(str_lit) {
-                            self.in_function_with_directive = true;
-
-                            // Validate that function is async
-                            if !func.is_async {
-                                emit_error(RunnerErrorKind::NonAsyncFunction { span: *span });
-                            }
-
-                            // Remove the directive
-                            Self::remove_directive_from_stmts(&mut body.stmts);
-                        } else if Self::check_directive_typo(str_lit) {
-                            // Found a typo in function body
-                            emit_error(RunnerErrorKind::MisspelledDirective {
-                                span: *span,
-                                directive: str_lit.value.to_string(),
-                            });
-                            // Remove the misspelled directive
-                            Self::remove_directive_from_stmts(&mut body.stmts);
-                        }
-                    }
-                }
-            }
+            let directive_span =
+                self.process_prologue(&mut body.stmts, as_stmt, DirectiveLocation::FunctionBody);
+
+            if let Some(span) = directive_span {
+                self.in_function_with_directive = true;
+                self.found_directive = true;
 
-            // Check for misplaced directive (not first statement)
-            let mut found_misplaced = false;
-            for (index, stmt) in body.stmts.iter().enumerate() {
-                if index > 0 {
-                    if let Stmt::Expr(ExprStmt { expr, span }) = stmt {
-                        if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                            if Self::is_use_runner_directive(str_lit) {
-                                emit_error(RunnerErrorKind::MisplacedDirective {
-                                    span: *span,
-                                    location: DirectiveLocation::FunctionBody,
-                                });
-                                found_misplaced = true;
-                            }
-                        }
-                    }
+                // Validate that function is async
+                if !func.is_async {
+                    emit_error(RunnerErrorKind::NonAsyncFunction { span });
                 }
             }
-            // Remove misplaced directives if found
-            if found_misplaced {
-                Self::remove_directive_from_stmts(&mut body.stmts);
-            }
         }
 
         func.visit_mut_children_with(self);
@@ -286,53 +583,40 @@ impl VisitMut for RunnerTransform {
     fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
         // For arrow functions, check if body starts with directive
         if let BlockStmtOrExpr::BlockStmt(block) = arrow.body.as_mut() {
-            // Check first statement for directive
-            if let Some(first_stmt) = block.stmts.first() {
-                if let Stmt::Expr(ExprStmt { expr, span }) = first_stmt {
-                    if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                        if Self::is_use_runner_directive(str_lit) {
-                            // Check if arrow function is async
-                            // Note: arrow functions don't have is_async flag, so we check the parent
-                            // For now, we'll just validate and remove
-                            // TODO: Could check parent context to see if it's async
-                        } else if Self::check_directive_typo(str_lit) {
-                            emit_error(RunnerErrorKind::MisspelledDirective {
-                                span: *span,
-                                directive: str_lit.value.to_string(),
-                            });
-                            // Remove the misspelled directive
-                            Self::remove_directive_from_stmts(&mut block.stmts);
-                        }
-                    }
-                }
+            let directive_span =
+                self.process_prologue(&mut block.stmts, as_stmt, DirectiveLocation::FunctionBody);
+
+            if directive_span.is_some() {
+                self.found_directive = true;
+                // Check if arrow function is async
+                // Note: arrow functions don't have is_async flag, so we check the parent
+                // For now, we'll just validate and remove
+                // TODO: Could check parent context to see if it's async
             }
+        }
 
-            // Check for misplaced directive
-            let mut found_misplaced = false;
-            for (index, stmt) in block.stmts.iter().enumerate() {
-                if index > 0 {
-                    if let Stmt::Expr(ExprStmt { expr, span }) = stmt {
-                        if let Expr::Lit(Lit::Str(str_lit)) = expr.as_ref() {
-                            if Self::is_use_runner_directive(str_lit) {
-                                emit_error(RunnerErrorKind::MisplacedDirective {
-                                    span: *span,
-                                    location: DirectiveLocation::FunctionBody,
-                                });
-                                found_misplaced = true;
-                            }
-                        }
-                    }
-                }
-            }
-            // Remove misplaced directives if found
-            if found_misplaced {
-                Self::remove_directive_from_stmts(&mut block.stmts);
-            }
+        arrow.visit_mut_children_with(self);
+    }
 
-            Self::remove_directive_from_stmts(&mut block.stmts);
+    fn visit_mut_constructor(&mut self, constructor: &mut Constructor) {
+        if let Some(body) = &mut constructor.body {
+            self.visit_sync_body(body);
         }
+        constructor.visit_mut_children_with(self);
+    }
 
-        arrow.visit_mut_children_with(self);
+    fn visit_mut_getter_prop(&mut self, getter: &mut GetterProp) {
+        if let Some(body) = &mut getter.body {
+            self.visit_sync_body(body);
+        }
+        getter.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_setter_prop(&mut self, setter: &mut SetterProp) {
+        if let Some(body) = &mut setter.body {
+            self.visit_sync_body(body);
+        }
+        setter.visit_mut_children_with(self);
     }
 }
 
@@ -383,7 +667,20 @@ pub fn process_transform(
     // Normalize path separators to forward slashes for consistency
     let normalized_filename = relative_filename.replace('\\', "/");
 
-    let mut visitor = RunnerTransform::new(normalized_filename);
+    let config = metadata
+        .get_transform_plugin_config()
+        .map(|json| {
+            serde_json::from_str::<WasmConfig>(&json)
+                .expect("invalid config for swc_plugin_runners")
+        })
+        .unwrap_or_default();
+
+    let mut visitor = RunnerTransform::new(normalized_filename)
+        .with_config(config)
+        .with_source_map(Lrc::new(metadata.source_map.clone()));
+    if let Some(comments) = metadata.comments {
+        visitor = visitor.with_comments(comments);
+    }
     program.visit_mut_with(&mut visitor);
     program
 }
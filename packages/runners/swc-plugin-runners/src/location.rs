@@ -0,0 +1,80 @@
+//! Source locations attached to runner functions with `sourceLocations`.
+//!
+//! Bundling and minification lose the file a runner came from, so harnesses
+//! read it back from the function itself to attribute errors and logs:
+//!
+//! ```js
+//! Object.defineProperty(fetchUser, "__runnerLocation", {
+//!     value: {
+//!         file: "src/runners/user.ts",
+//!         line: 7,
+//!         column: 1,
+//!         runnerId: "src/runners/user.ts#fetchUser"
+//!     }
+//! });
+//! ```
+//!
+//! `Object.defineProperty` makes the property non-enumerable, so it stays out
+//! of spreads, `Object.keys` and serialized results.
+
+use crate::{
+    metadata::FileScan,
+    rewrite::{arg, call, field, num_lit, object, path_expr, str_lit},
+};
+use std::collections::HashSet;
+use swc_core::{
+    common::{errors::SourceMapperDyn, sync::Lrc, DUMMY_SP},
+    ecma::ast::*,
+};
+
+const LOCATION_PROPERTY: &str = "__runnerLocation";
+
+/// `Object.defineProperty(runner, "__runnerLocation", { value: { ... } })` for
+/// every runner, in the same order as the manifest. `default_reference`
+/// refers to an anonymous default export. Positions are 1-based, like the
+/// schema extractor's, and come from `source_map`; they are 0 without it.
+/// They are those of the runner's declaration, so `line` is the one in the
+/// manifest and the `__runners` registry.
+pub(crate) fn location_stmts(
+    scan: &FileScan,
+    default_reference: Option<&str>,
+    source_map: Option<&Lrc<SourceMapperDyn>>,
+) -> Vec<Stmt> {
+    let file = &scan.metadata.file;
+    // A function exported under several names keeps the first, since the
+    // property can only be defined once
+    let mut seen = HashSet::new();
+
+    scan.metadata
+        .runners
+        .iter()
+        .zip(&scan.runners)
+        .filter(|(_, site)| seen.insert(site.span.lo))
+        .filter_map(|(runner, site)| {
+            let reference = site.reference.as_deref().or(default_reference)?;
+            let (line, column) = source_map.map_or((0, 0), |cm| {
+                let loc = cm.lookup_char_pos(site.declared_at.lo);
+                (loc.line, loc.col.0 + 1)
+            });
+
+            let location = object(vec![
+                field("file", str_lit(file)),
+                field("line", num_lit(line)),
+                field("column", num_lit(column)),
+                field("runnerId", str_lit(&runners_model::runner_id(file, &runner.name))),
+            ]);
+            let define = call(
+                path_expr("Object.defineProperty"),
+                vec![
+                    arg(path_expr(reference)),
+                    arg(str_lit(LOCATION_PROPERTY)),
+                    arg(object(vec![field("value", location)])),
+                ],
+            );
+            Some(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: define,
+            }))
+        })
+        .collect()
+}
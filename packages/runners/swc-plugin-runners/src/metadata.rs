@@ -0,0 +1,617 @@
+//! Runner and schema metadata collected from the SWC AST.
+//!
+//! Mirrors what `schema_extractor` reports from its tree-sitter pass, so
+//! builds that already run this plugin get the same `runner-schemas.json`
+//! data without parsing every file a second time. Both follow the rules in
+//! `runners_model`.
+
+use crate::prologue::{as_stmt, DirectivePrologue};
+use runners_model::{
+    is_schema_name, schema_runner_name, RunnerCandidate, RunnerInfo,
+    SchemaInfo, SchemaMetadata, DEFAULT_EXPORT_NAME,
+};
+use std::collections::HashSet;
+use swc_core::{
+    common::{errors::SourceMapperDyn, sync::Lrc, BytePos, Span, Spanned},
+    ecma::ast::*,
+};
+
+fn line_of(span: Span, source_map: Option<&Lrc<SourceMapperDyn>>) -> usize {
+    source_map.map_or(0, |cm| cm.lookup_char_pos(span.lo).line)
+}
+
+fn body_has_directive(body: Option<&BlockStmt>) -> bool {
+    body.is_some_and(|block| {
+        DirectivePrologue::of(&block.stmts, as_stmt)
+            .runner_directive()
+            .is_some()
+    })
+}
+
+/// What the runner rules need from a function-valued initializer.
+struct FunctionValue {
+    /// Span of the function itself, inside any wrapping call
+    span: Span,
+    is_async: bool,
+    function_directive: bool,
+    /// Schema argument of a runner factory call
+    input_schema: Option<String>,
+}
+
+/// Unwraps parenthesized, `satisfies` and `as` expressions and calls to one of
+/// `runner_factories`, such as `defineRunner(inputSchema, async (ctx) => {})`.
+/// A factory's function is its last argument; a leading identifier argument
+/// is taken as the input schema.
+fn unwrap_function_value(expr: &Expr, runner_factories: &[String]) -> Option<FunctionValue> {
+    match expr {
+        Expr::Arrow(arrow) => Some(FunctionValue {
+            span: arrow.span,
+            is_async: arrow.is_async,
+            function_directive: match arrow.body.as_ref() {
+                BlockStmtOrExpr::BlockStmt(block) => body_has_directive(Some(block)),
+                _ => false,
+            },
+            input_schema: None,
+        }),
+        Expr::Fn(fn_expr) => Some(FunctionValue {
+            span: fn_expr.function.span,
+            is_async: fn_expr.function.is_async,
+            function_directive: body_has_directive(fn_expr.function.body.as_ref()),
+            input_schema: None,
+        }),
+        Expr::Paren(ParenExpr { expr, .. })
+        | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. })
+        | Expr::TsAs(TsAsExpr { expr, .. }) => unwrap_function_value(expr, runner_factories),
+        Expr::Call(call) => {
+            let Callee::Expr(callee) = &call.callee else {
+                return None;
+            };
+            let Expr::Ident(callee) = callee.as_ref() else {
+                return None;
+            };
+            if !runner_factories.iter().any(|factory| *factory == *callee.sym) {
+                return None;
+            }
+
+            let (function, rest) = call.args.split_last()?;
+            if function.spread.is_some() {
+                return None;
+            }
+            let mut value = unwrap_function_value(&function.expr, runner_factories)?;
+            if value.input_schema.is_none() {
+                value.input_schema = rest.first().and_then(|arg| match arg.expr.as_ref() {
+                    Expr::Ident(ident) if arg.spread.is_none() => Some(ident.sym.to_string()),
+                    _ => None,
+                });
+            }
+            Some(value)
+        }
+        _ => None,
+    }
+}
+
+/// A function bound to a name, checked against the runner rules once we know
+/// how it is exported.
+struct FunctionBinding {
+    /// Local name, used to resolve `export { name }` lists
+    name: String,
+    /// Name the function is exported under, if it is exported where it is declared
+    export_name: Option<String>,
+    /// See [`RunnerSite::declared_at`]
+    declared_at: Span,
+    /// Span of the function, see [`RunnerSite::span`]
+    span: Span,
+    /// See [`RunnerSite::reference`]
+    reference: Option<String>,
+    is_async: bool,
+    function_directive: bool,
+    input_schema: Option<String>,
+    /// Schema named by a `Runner<typeof Schema>` annotation on the binding
+    annotated_schema: Option<String>,
+}
+
+/// The schema `Schema` of a `Runner<typeof Schema>` type annotation.
+fn annotated_schema(type_ann: Option<&TsTypeAnn>) -> Option<String> {
+    let TsType::TsTypeRef(type_ref) = type_ann?.type_ann.as_ref() else {
+        return None;
+    };
+    let runner_type = match &type_ref.type_name {
+        TsEntityName::Ident(ident) => &ident.sym,
+        TsEntityName::TsQualifiedName(name) => &name.right.sym,
+    };
+    if &**runner_type != "Runner" {
+        return None;
+    }
+
+    match type_ref.type_params.as_ref()?.params.first()?.as_ref() {
+        TsType::TsTypeQuery(TsTypeQuery {
+            expr_name: TsTypeQueryExpr::TsEntityName(schema),
+            type_args: None,
+            ..
+        }) => Some(entity_name_str(schema)),
+        _ => None,
+    }
+}
+
+fn entity_name_str(name: &TsEntityName) -> String {
+    match name {
+        TsEntityName::Ident(ident) => ident.sym.to_string(),
+        TsEntityName::TsQualifiedName(name) => {
+            format!("{}.{}", entity_name_str(&name.left), name.right.sym)
+        }
+    }
+}
+
+/// `module.exports`
+fn is_module_exports(member: &MemberExpr) -> bool {
+    matches!(member.obj.as_ref(), Expr::Ident(obj) if &*obj.sym == "module")
+        && matches!(&member.prop, MemberProp::Ident(prop) if &*prop.sym == "exports")
+}
+
+/// `exports`
+fn is_exports_identifier(expr: &Expr) -> bool {
+    matches!(expr, Expr::Ident(ident) if &*ident.sym == "exports")
+}
+
+/// `module.exports`, as an expression
+fn is_module_exports_expr(expr: &Expr) -> bool {
+    matches!(expr, Expr::Member(member) if is_module_exports(member))
+}
+
+/// Start of the last top-level `module.exports = ...` statement. Exports
+/// assigned to the replaced object before it, and to `exports` anywhere, are
+/// lost once it runs.
+fn last_exports_reassignment<'a>(stmts: impl Iterator<Item = &'a Stmt>) -> Option<BytePos> {
+    stmts
+        .filter(|stmt| {
+            let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+                return false;
+            };
+            matches!(
+                expr.as_ref(),
+                Expr::Assign(AssignExpr {
+                    op: AssignOp::Assign,
+                    left: AssignTarget::Simple(SimpleAssignTarget::Member(target)),
+                    ..
+                }) if is_module_exports(target)
+            )
+        })
+        .last()
+        .map(|stmt| stmt.span_lo())
+}
+
+fn export_name_str(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(str_lit) => str_lit.value.to_string(),
+    }
+}
+
+/// Declarations found at module scope.
+#[derive(Default)]
+struct ModuleScope<'a> {
+    source_map: Option<&'a Lrc<SourceMapperDyn>>,
+    runner_factories: &'a [String],
+    /// See [`last_exports_reassignment`]
+    exports_reassigned_at: Option<BytePos>,
+    bindings: Vec<FunctionBinding>,
+    /// `export { local as exported }` lists, as (local, exported) pairs
+    export_lists: Vec<(String, String)>,
+    /// Exported variables with a schema-like name, with their line
+    schema_decls: Vec<(String, usize)>,
+    /// Variables that can be referred to from module scope: those declared
+    /// there and the exported members of exported namespaces
+    variables: HashSet<String>,
+}
+
+/// A runner function, located for the transforms that rewrite runners.
+pub(crate) struct RunnerSite {
+    /// Name the runner is exported under, as in [`RunnerInfo::name`]
+    pub name: String,
+    /// Span of the declaration the runner is reported at, such as the
+    /// `const` statement of `export const run = async () => {}`; its start
+    /// gives both [`RunnerInfo::line`] and the runner's source location
+    pub declared_at: Span,
+    /// Span of the function node, which identifies it while rewriting; the
+    /// same function may be exported under several names
+    pub span: Span,
+    /// How to refer to the function from module scope, such as `checkTitle`,
+    /// `Billing.charge` or `module.exports.checkTitle`; `None` for an
+    /// anonymous default export
+    pub reference: Option<String>,
+    /// Schema named by a `Runner<typeof Schema>` annotation
+    pub annotated_schema: Option<String>,
+}
+
+/// Everything the transform learns from one pass over module scope.
+pub(crate) struct FileScan {
+    pub metadata: SchemaMetadata,
+    /// Runners in the same order as `metadata.runners`
+    pub runners: Vec<RunnerSite>,
+    /// Variables that can be referred to from module scope, by their
+    /// `Namespace.member` name inside namespaces; used to find a runner's
+    /// `<name>InputSchema`
+    pub variables: HashSet<String>,
+}
+
+impl ModuleScope<'_> {
+    /// Visit the items of the module or of a `namespace` block. Members of a
+    /// namespace are named `Namespace.member`; function bodies are never
+    /// entered. `scope_exported` is whether every enclosing namespace is
+    /// exported.
+    fn collect_items(&mut self, items: &[ModuleItem], prefix: &str, scope_exported: bool) {
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                    self.collect_decl(decl, prefix, scope_exported)
+                }
+                ModuleItem::Stmt(stmt) => self.collect_stmt(stmt, prefix),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(fn_expr),
+                    ..
+                })) => {
+                    let name = fn_expr.ident.as_ref().map_or_else(
+                        || DEFAULT_EXPORT_NAME.to_string(),
+                        |ident| ident.sym.to_string(),
+                    );
+                    self.bindings.push(FunctionBinding {
+                        export_name: Some(name.clone()),
+                        name,
+                        declared_at: fn_expr.function.span,
+                        span: fn_expr.function.span,
+                        reference: fn_expr.ident.as_ref().map(|ident| ident.sym.to_string()),
+                        is_async: fn_expr.function.is_async,
+                        function_directive: body_has_directive(fn_expr.function.body.as_ref()),
+                        input_schema: None,
+                        annotated_schema: None,
+                    });
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    expr,
+                    span,
+                })) => {
+                    if let Some(value) = unwrap_function_value(expr, self.runner_factories) {
+                        self.bindings.push(FunctionBinding {
+                            name: DEFAULT_EXPORT_NAME.to_string(),
+                            export_name: Some(DEFAULT_EXPORT_NAME.to_string()),
+                            declared_at: *span,
+                            span: value.span,
+                            reference: None,
+                            is_async: value.is_async,
+                            function_directive: value.function_directive,
+                            input_schema: value.input_schema,
+                            annotated_schema: None,
+                        });
+                    }
+                }
+                // `export { local as alias }`, but not `export { x } from "./other"`
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                })) if prefix.is_empty() => {
+                    for specifier in specifiers {
+                        if let ExportSpecifier::Named(named) = specifier {
+                            let local = export_name_str(&named.orig);
+                            let exported = named
+                                .exported
+                                .as_ref()
+                                .map(export_name_str)
+                                .unwrap_or_else(|| local.clone());
+                            self.export_lists.push((local, exported));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn collect_decl(&mut self, decl: &Decl, prefix: &str, exported: bool) {
+        match decl {
+            Decl::Fn(fn_decl) => {
+                let name = format!("{prefix}{}", fn_decl.ident.sym);
+                self.bindings.push(FunctionBinding {
+                    export_name: exported.then(|| name.clone()),
+                    reference: Some(name.clone()),
+                    name,
+                    declared_at: fn_decl.function.span,
+                    span: fn_decl.function.span,
+                    is_async: fn_decl.function.is_async,
+                    function_directive: body_has_directive(fn_decl.function.body.as_ref()),
+                    input_schema: None,
+                    annotated_schema: None,
+                });
+            }
+            Decl::Var(var) => {
+                for declarator in &var.decls {
+                    let Pat::Ident(binding) = &declarator.name else {
+                        continue;
+                    };
+                    let name = format!("{prefix}{}", binding.id.sym);
+                    if prefix.is_empty() || exported {
+                        self.variables.insert(name.clone());
+                    }
+
+                    let value = declarator
+                        .init
+                        .as_deref()
+                        .and_then(|init| unwrap_function_value(init, self.runner_factories));
+                    if let Some(value) = value {
+                        self.bindings.push(FunctionBinding {
+                            export_name: exported.then(|| name.clone()),
+                            reference: Some(name.clone()),
+                            name,
+                            declared_at: var.span,
+                            span: value.span,
+                            is_async: value.is_async,
+                            function_directive: value.function_directive,
+                            input_schema: value.input_schema,
+                            annotated_schema: annotated_schema(binding.type_ann.as_deref()),
+                        });
+                    } else if exported && is_schema_name(&name) {
+                        self.schema_decls.push((name, line_of(var.span, self.source_map)));
+                    }
+                }
+            }
+            // `declare namespace` has no runtime members
+            Decl::TsModule(ts_module) if !ts_module.declare => {
+                if let (TsModuleName::Ident(id), Some(body)) = (&ts_module.id, &ts_module.body) {
+                    self.collect_namespace(body, &format!("{prefix}{}.", id.sym), exported);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_namespace(&mut self, body: &TsNamespaceBody, prefix: &str, exported: bool) {
+        match body {
+            TsNamespaceBody::TsModuleBlock(block) => self.collect_items(&block.body, prefix, exported),
+            // `namespace A.B {}`
+            TsNamespaceBody::TsNamespaceDecl(decl) => {
+                self.collect_namespace(&decl.body, &format!("{prefix}{}.", decl.id.sym), exported)
+            }
+        }
+    }
+    /// A statement of the module or of a namespace block. Top-level CommonJS
+    /// `exports.name = ...` and `module.exports = { ... }` assignments count
+    /// as exports.
+    fn collect_stmt(&mut self, stmt: &Stmt, prefix: &str) {
+        match stmt {
+            Stmt::Decl(decl) => self.collect_decl(decl, prefix, false),
+            Stmt::Expr(ExprStmt { expr, span }) if prefix.is_empty() => {
+                if let Expr::Assign(assign) = expr.as_ref() {
+                    self.collect_commonjs_export(assign, *span);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// `exports.name = ...`, `module.exports.name = ...` and
+    /// `module.exports = { ... }`, unless a later `module.exports = ...`
+    /// replaces the object they were assigned to.
+    fn collect_commonjs_export(&mut self, assign: &AssignExpr, span: Span) {
+        if assign.op != AssignOp::Assign {
+            return;
+        }
+        let AssignTarget::Simple(SimpleAssignTarget::Member(target)) = &assign.left else {
+            return;
+        };
+
+        if is_module_exports(target) {
+            // `module.exports = { ... }`
+            if self.exports_reassigned_at != Some(span.lo) {
+                return;
+            }
+            if let Expr::Object(object) = assign.right.as_ref() {
+                for prop in &object.props {
+                    self.collect_export_prop(prop);
+                }
+            }
+        } else if match self.exports_reassigned_at {
+            None => is_exports_identifier(&target.obj) || is_module_exports_expr(&target.obj),
+            // `exports` still points at the replaced object
+            Some(reassigned_at) => span.lo > reassigned_at && is_module_exports_expr(&target.obj),
+        } {
+            // `exports.name = ...` and `module.exports.name = ...`
+            if let MemberProp::Ident(name) = &target.prop {
+                self.collect_commonjs_value(name.sym.to_string(), &assign.right, span);
+            }
+        }
+    }
+
+    /// A property of an object literal assigned to `module.exports`.
+    fn collect_export_prop(&mut self, prop: &PropOrSpread) {
+        let PropOrSpread::Prop(prop) = prop else {
+            return;
+        };
+        match prop.as_ref() {
+            Prop::Shorthand(ident) => {
+                let name = ident.sym.to_string();
+                self.export_lists.push((name.clone(), name));
+            }
+            Prop::KeyValue(KeyValueProp {
+                key: PropName::Ident(key),
+                value,
+            }) => {
+                self.collect_commonjs_value(key.sym.to_string(), value, key.span);
+            }
+            Prop::Method(MethodProp {
+                key: PropName::Ident(key),
+                function,
+            }) => {
+                let name = key.sym.to_string();
+                self.bindings.push(FunctionBinding {
+                    export_name: Some(name.clone()),
+                    reference: Some(format!("module.exports.{name}")),
+                    name,
+                    declared_at: key.span,
+                    span: function.span,
+                    is_async: function.is_async,
+                    function_directive: body_has_directive(function.body.as_ref()),
+                    input_schema: None,
+                    annotated_schema: None,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// A value exported under `name` through CommonJS. Identifiers export a
+    /// local binding, like `export { local as name }`.
+    fn collect_commonjs_value(&mut self, name: String, value: &Expr, declared_at: Span) {
+        if let Expr::Ident(local) = value {
+            self.export_lists.push((local.sym.to_string(), name));
+        } else if let Some(value) = unwrap_function_value(value, self.runner_factories) {
+            self.bindings.push(FunctionBinding {
+                export_name: Some(name.clone()),
+                reference: Some(format!("module.exports.{name}")),
+                name,
+                declared_at,
+                span: value.span,
+                is_async: value.is_async,
+                function_directive: value.function_directive,
+                input_schema: value.input_schema,
+                annotated_schema: None,
+            });
+        } else if is_schema_name(&name) {
+            self.schema_decls.push((name, line_of(declared_at, self.source_map)));
+        }
+    }
+
+    /// Check the collected bindings against the runner rules.
+    fn into_scan(self, file: &str, module_directive: bool) -> FileScan {
+        let mut runners = Vec::new();
+        for binding in &self.bindings {
+            let export_names = binding.export_name.iter().cloned().chain(
+                self.export_lists
+                    .iter()
+                    .filter(|(local, _)| *local == binding.name)
+                    .map(|(_, exported)| exported.clone()),
+            );
+            for name in export_names {
+                let candidate = RunnerCandidate {
+                    exported: true,
+                    is_async: binding.is_async,
+                    module_directive,
+                    function_directive: binding.function_directive,
+                };
+                if candidate.is_runner() {
+                    runners.push((
+                        RunnerInfo {
+                            name: name.clone(),
+                            line: line_of(binding.declared_at, self.source_map),
+                            input_schema: binding.input_schema.clone(),
+                        },
+                        RunnerSite {
+                            name,
+                            declared_at: binding.declared_at,
+                            span: binding.span,
+                            reference: binding.reference.clone(),
+                            annotated_schema: binding.annotated_schema.clone(),
+                        },
+                    ));
+                }
+            }
+        }
+        runners.sort_by_key(|(runner, _)| runner.line);
+        let (runners, sites): (Vec<_>, Vec<_>) = runners.into_iter().unzip();
+
+        let schemas = self
+            .schema_decls
+            .into_iter()
+            .map(|(name, line)| SchemaInfo {
+                runner_name: schema_runner_name(&name, &runners).map(str::to_string),
+                name,
+                line,
+            })
+            .collect();
+
+        FileScan {
+            metadata: SchemaMetadata {
+                file: file.to_string(),
+                runners,
+                schemas,
+                partial: false,
+            },
+            runners: sites,
+            variables: self.variables,
+        }
+    }
+}
+
+/// Collect exported runners and exported `*Schema` variables from a module.
+///
+/// Only module-scope declarations count, including members of exported
+/// `namespace` blocks. Covers `export function`/`export const` declarations,
+/// default exports, local `export { name as alias }` lists and CommonJS
+/// exports, with initializers unwrapped as in `schema_extractor` (parentheses,
+/// `satisfies`, `as` and calls to `runner_factories`). A named default export
+/// keeps its own name; an anonymous one is reported as `default`. Must run
+/// before directives are removed. Line numbers come from `source_map` and are
+/// 0 when none is available.
+pub fn collect_metadata(
+    module: &Module,
+    file: &str,
+    runner_factories: &[String],
+    source_map: Option<&Lrc<SourceMapperDyn>>,
+) -> SchemaMetadata {
+    scan_module(module, file, runner_factories, source_map).metadata
+}
+
+/// [`collect_metadata`] along with where each runner is in the AST.
+pub(crate) fn scan_module(
+    module: &Module,
+    file: &str,
+    runner_factories: &[String],
+    source_map: Option<&Lrc<SourceMapperDyn>>,
+) -> FileScan {
+    let module_directive = DirectivePrologue::of(&module.body, ModuleItem::as_stmt)
+        .runner_directive()
+        .is_some();
+
+    let mut scope = ModuleScope {
+        source_map,
+        runner_factories,
+        exports_reassigned_at: last_exports_reassignment(module.body.iter().filter_map(ModuleItem::as_stmt)),
+        ..Default::default()
+    };
+    scope.collect_items(&module.body, "", true);
+    scope.into_scan(file, module_directive)
+}
+
+/// Like [`collect_metadata`], for a CommonJS script: runners are exported
+/// through `exports.name = ...`, `module.exports.name = ...` or
+/// `module.exports = { ... }`.
+pub fn collect_script_metadata(
+    script: &Script,
+    file: &str,
+    runner_factories: &[String],
+    source_map: Option<&Lrc<SourceMapperDyn>>,
+) -> SchemaMetadata {
+    scan_script(script, file, runner_factories, source_map).metadata
+}
+
+/// [`collect_script_metadata`] along with where each runner is in the AST.
+pub(crate) fn scan_script(
+    script: &Script,
+    file: &str,
+    runner_factories: &[String],
+    source_map: Option<&Lrc<SourceMapperDyn>>,
+) -> FileScan {
+    let module_directive = DirectivePrologue::of(&script.body, as_stmt)
+        .runner_directive()
+        .is_some();
+
+    let mut scope = ModuleScope {
+        source_map,
+        runner_factories,
+        exports_reassigned_at: last_exports_reassignment(script.body.iter()),
+        ..Default::default()
+    };
+    for stmt in &script.body {
+        scope.collect_stmt(stmt, "");
+    }
+    scope.into_scan(file, module_directive)
+}
@@ -0,0 +1,148 @@
+//! The ECMAScript directive prologue of a module or function body.
+//!
+//! A prologue is the run of string-literal expression statements at the top of
+//! a body, e.g. `"use strict"; "use client"; "use runner";`. Every entry is a
+//! directive regardless of order, so `"use runner"` may follow other directives.
+//! The first statement of any other kind ends the prologue; a parenthesized
+//! string or a template literal is not a directive.
+
+use runners_model::is_use_runner_directive;
+use swc_core::{common::Span, ecma::ast::*};
+
+/// A directive-like statement reported by [`PrologueAnalysis`].
+pub(crate) struct FoundDirective {
+    pub span: Span,
+    pub value: String,
+}
+
+/// Every directive-related fact about a statement list, gathered in one pass.
+///
+/// Statements are classified once: inside the prologue as `"use runner"`, a
+/// typo of it, or another directive; after it only string statements that
+/// look like `"use runner"` are of interest, as misplaced directives.
+pub(crate) struct PrologueAnalysis {
+    /// Span of the first `"use runner"` directive in the prologue
+    pub runner_directive: Option<Span>,
+    /// Misspelled `"use runner"` directives in the prologue
+    pub typos: Vec<FoundDirective>,
+    /// `"use runner"` or a typo of it after the prologue, left in place
+    pub misplaced: Vec<FoundDirective>,
+    /// Prologue indices of the directives to remove (`"use runner"` and typos)
+    removable: Vec<usize>,
+}
+
+impl PrologueAnalysis {
+    /// Classify the statements of `items`; `is_typo` decides whether a
+    /// directive value is a misspelled `"use runner"`.
+    pub fn of<T>(
+        items: &[T],
+        as_stmt: fn(&T) -> Option<&Stmt>,
+        is_typo: impl Fn(&str) -> bool,
+    ) -> Self {
+        let mut analysis = Self {
+            runner_directive: None,
+            typos: Vec::new(),
+            misplaced: Vec::new(),
+            removable: Vec::new(),
+        };
+        let mut in_prologue = true;
+
+        for (index, item) in items.iter().enumerate() {
+            let directive = as_stmt(item).and_then(as_directive);
+            let Some(directive) = directive else {
+                in_prologue = false;
+                continue;
+            };
+
+            let value: &str = &directive.str_lit.value;
+            let is_runner = is_use_runner_directive(value);
+            let typo = !is_runner && is_typo(value);
+            if !is_runner && !typo {
+                continue;
+            }
+
+            let found = FoundDirective {
+                span: directive.span,
+                value: value.to_string(),
+            };
+            if !in_prologue {
+                analysis.misplaced.push(found);
+                continue;
+            }
+
+            analysis.removable.push(index);
+            if typo {
+                analysis.typos.push(found);
+            } else if analysis.runner_directive.is_none() {
+                analysis.runner_directive = Some(found.span);
+            }
+        }
+
+        analysis
+    }
+
+    /// Remove `"use runner"` and misspelled directives from the prologue of
+    /// the `items` this analysis was made from, keeping other directives.
+    pub fn remove_directives<T>(&self, items: &mut Vec<T>) {
+        let Some(&last) = self.removable.last() else {
+            return;
+        };
+        // Only the prologue is rebuilt, so the rest of a large body is moved once
+        let kept: Vec<T> = items
+            .drain(..=last)
+            .enumerate()
+            .filter(|(index, _)| !self.removable.contains(index))
+            .map(|(_, item)| item)
+            .collect();
+        items.splice(..0, kept);
+    }
+}
+
+/// A string-literal statement in a directive prologue.
+pub(crate) struct Directive<'a> {
+    pub str_lit: &'a Str,
+    pub span: Span,
+}
+
+/// The directive prologue of a statement list.
+pub(crate) struct DirectivePrologue<'a> {
+    pub directives: Vec<Directive<'a>>,
+}
+
+impl<'a> DirectivePrologue<'a> {
+    /// The prologue of `items`, using `as_stmt` to see module items as statements.
+    pub fn of<T>(items: &'a [T], as_stmt: fn(&T) -> Option<&Stmt>) -> Self {
+        let directives = items
+            .iter()
+            .map_while(|item| as_stmt(item).and_then(as_directive))
+            .collect();
+        Self { directives }
+    }
+
+    /// Span of the first `"use runner"` directive, if the prologue has one.
+    pub fn runner_directive(&self) -> Option<Span> {
+        self.directives
+            .iter()
+            .find(|directive| is_use_runner_directive(&directive.str_lit.value))
+            .map(|directive| directive.span)
+    }
+}
+
+/// A string-literal expression statement, the form directives take.
+pub(crate) fn as_directive(stmt: &Stmt) -> Option<Directive<'_>> {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, span }) => match expr.as_ref() {
+            Expr::Lit(Lit::Str(str_lit)) => Some(Directive {
+                str_lit,
+                span: *span,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Counterpart of `ModuleItem::as_stmt` for function bodies.
+pub(crate) fn as_stmt(stmt: &Stmt) -> Option<&Stmt> {
+    Some(stmt)
+}
@@ -0,0 +1,205 @@
+//! The `__runners` export appended to runner files with `registerRunners`.
+//!
+//! Lets a handler build its registry from imports alone, without scanning
+//! the filesystem or reading `runner-schemas.json`, which serverless bundles
+//! can't do:
+//!
+//! ```js
+//! export const __runners = {
+//!     "src/runners/user.ts#fetchUser": {
+//!         fn: fetchUser,
+//!         name: "fetchUser",
+//!         file: "src/runners/user.ts",
+//!         line: 7,
+//!         inputSchema: fetchUserInputSchema
+//!     }
+//! };
+//! ```
+//!
+//! CommonJS scripts get `module.exports.__runners = { ... }` instead.
+
+use crate::{
+    metadata::{FileScan, RunnerSite},
+    rewrite::{arg, call, field, input_schema, num_lit, object, path_expr, prop, str_lit},
+};
+use runners_model::RunnerInfo;
+use swc_core::{common::DUMMY_SP, ecma::ast::*};
+
+const REGISTRY: &str = "__runners";
+
+/// Local name given to an anonymous default export so the registry can
+/// refer to it
+const DEFAULT_BINDING: &str = "__runners_default";
+
+/// The schema registered for a runner: the one it is validated against with
+/// `validateInput`, else the schema of its runner factory call, else the
+/// exported schema `schema_runner_name` matches to it.
+fn registered_schema(site: &RunnerSite, runner: &RunnerInfo, scan: &FileScan) -> Option<String> {
+    input_schema(site, &scan.variables)
+        .or_else(|| runner.input_schema.clone())
+        .or_else(|| {
+            scan.metadata
+                .schemas
+                .iter()
+                .find(|schema| {
+                    schema.runner_name.as_deref() == Some(runner.name.as_str())
+                        && scan.variables.contains(&schema.name)
+                })
+                .map(|schema| schema.name.clone())
+        })
+}
+
+fn const_decl(name: &str, init: Box<Expr>) -> Box<VarDecl> {
+    Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(Ident::from(name).into()),
+            init: Some(init),
+            definite: false,
+        }],
+        ..Default::default()
+    })
+}
+
+/// The registry object, keyed by runner ID. `default_reference` refers to
+/// an anonymous default export.
+fn registry(scan: &FileScan, default_reference: Option<&str>) -> Box<Expr> {
+    let file = &scan.metadata.file;
+    let entries = scan
+        .metadata
+        .runners
+        .iter()
+        .zip(&scan.runners)
+        .filter_map(|(runner, site)| {
+            let reference = site.reference.as_deref().or(default_reference)?;
+
+            let mut fields = vec![
+                field("fn", path_expr(reference)),
+                field("name", str_lit(&runner.name)),
+                field("file", str_lit(file)),
+                field("line", num_lit(runner.line)),
+            ];
+            if let Some(schema) = registered_schema(site, runner, scan) {
+                fields.push(field("inputSchema", path_expr(&schema)));
+            }
+
+            let runner_id = runners_model::runner_id(file, &runner.name);
+            Some(prop(PropName::Str(Str::from(runner_id.as_str())), object(fields)))
+        })
+        .collect();
+    object(entries)
+}
+
+/// If one of the runners is an anonymous default export, give it a local name
+/// and return it: `export default async function __runners_default() {}`, or
+/// `const __runners_default = ...;` followed by `export default
+/// __runners_default;` for an expression. A function named this way gets its
+/// `.name` of `"default"` back. Naming it again changes nothing.
+pub(crate) fn default_reference(items: &mut Vec<ModuleItem>, scan: &FileScan) -> Option<&'static str> {
+    if scan.runners.iter().all(|site| site.reference.is_some()) {
+        return None;
+    }
+    name_default_export(items);
+    Some(DEFAULT_BINDING)
+}
+
+fn name_default_export(items: &mut Vec<ModuleItem>) {
+    let Some(index) = items.iter().position(|item| {
+        matches!(
+            item,
+            ModuleItem::ModuleDecl(
+                ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(_),
+                    ..
+                }) | ModuleDecl::ExportDefaultExpr(_)
+            )
+        )
+    }) else {
+        return;
+    };
+
+    match &mut items[index] {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+            decl: DefaultDecl::Fn(fn_expr),
+            ..
+        })) if fn_expr.ident.is_none() => {
+            fn_expr.ident = Some(Ident::from(DEFAULT_BINDING));
+            items.insert(index + 1, keep_default_name());
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(export))
+            if !matches!(export.expr.as_ref(), Expr::Ident(_)) =>
+        {
+            let anonymous = is_anonymous_function(&export.expr);
+            let expr = std::mem::replace(
+                &mut export.expr,
+                Box::new(Expr::Ident(Ident::from(DEFAULT_BINDING))),
+            );
+            items.insert(
+                index,
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(const_decl(DEFAULT_BINDING, expr)))),
+            );
+            if anonymous {
+                items.insert(index + 1, keep_default_name());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether `expr` is a function that takes the name `default` when exported
+/// as is, and would take the name of a variable it is assigned to instead.
+fn is_anonymous_function(expr: &Expr) -> bool {
+    match expr {
+        Expr::Paren(paren) => is_anonymous_function(&paren.expr),
+        Expr::Arrow(_) => true,
+        Expr::Fn(fn_expr) => fn_expr.ident.is_none(),
+        _ => false,
+    }
+}
+
+/// `Object.defineProperty(__runners_default, "name", { value: "default" });`,
+/// so naming an anonymous default export leaves its `.name` as it was.
+fn keep_default_name() -> ModuleItem {
+    let define = call(
+        path_expr("Object.defineProperty"),
+        vec![
+            arg(path_expr(DEFAULT_BINDING)),
+            arg(str_lit("name")),
+            arg(object(vec![field("value", str_lit("default"))])),
+        ],
+    );
+    ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: define,
+    }))
+}
+
+/// Append `export const __runners = { ... }` to a module.
+pub(crate) fn append_module_registry(items: &mut Vec<ModuleItem>, scan: &FileScan) {
+    let default_reference = default_reference(items, scan);
+
+    items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span: DUMMY_SP,
+        decl: Decl::Var(const_decl(REGISTRY, registry(scan, default_reference))),
+    })));
+}
+
+/// Append `module.exports.__runners = { ... }` to a CommonJS script.
+pub(crate) fn append_script_registry(stmts: &mut Vec<Stmt>, scan: &FileScan) {
+    let target = MemberExpr {
+        obj: path_expr("module.exports"),
+        prop: MemberProp::Ident(IdentName::from(REGISTRY)),
+        ..Default::default()
+    };
+
+    stmts.push(Stmt::Expr(ExprStmt {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Assign(AssignExpr {
+            span: DUMMY_SP,
+            op: AssignOp::Assign,
+            left: AssignTarget::Simple(SimpleAssignTarget::Member(target)),
+            right: registry(scan, None),
+        })),
+    }));
+}
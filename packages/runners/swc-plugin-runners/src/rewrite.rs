@@ -0,0 +1,369 @@
+//! Rewrites runner functions for the opt-in runtime modes.
+//!
+//! Runners are the ones [`crate::metadata`] finds, matched by the span of
+//! their function. A rewritten runner keeps its name, its export and its
+//! `async` keyword. Its parameters and body move into an inner async arrow,
+//! which goes through the helpers in `src/helpers/` and is called with the
+//! original arguments:
+//!
+//! ```js
+//! export async function checkTitle(...__runnerArgs) {
+//!     return __runners_validateInput("runners/title.ts#checkTitle", checkTitleInputSchema, async (ctx, input)=>{
+//!         // original body
+//!     })(...__runnerArgs);
+//! }
+//! ```
+//!
+//! The arrow keeps `this` and `arguments` pointing at the runner's own. Each
+//! helper a file uses is appended to it once, after everything else, so the
+//! start of the file still carries the manifest comment.
+
+use crate::{
+    metadata::{FileScan, RunnerSite},
+    TransformMode, WasmConfig,
+};
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    sync::OnceLock,
+};
+use swc_core::{
+    common::{errors::HANDLER, sync::Lrc, BytePos, FileName, SourceMap, Span, DUMMY_SP},
+    ecma::{
+        ast::*,
+        parser::{parse_file_as_script, Syntax},
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+/// Rest parameter a rewritten runner collects its arguments in
+const ARGS: &str = "__runnerArgs";
+
+/// Runtime helpers, each declaring one function named [`Helper::name`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Helper {
+    ValidateInput,
+    NormalizeResult,
+    Execute,
+}
+
+impl Helper {
+    fn name(self) -> &'static str {
+        match self {
+            Helper::ValidateInput => "__runners_validateInput",
+            Helper::NormalizeResult => "__runners_normalizeResult",
+            Helper::Execute => "__runners_execute",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Helper::ValidateInput => include_str!("helpers/validate_input.js"),
+            Helper::NormalizeResult => include_str!("helpers/normalize_result.js"),
+            Helper::Execute => include_str!("helpers/execute.js"),
+        }
+    }
+
+    /// The helper's declaration, without spans from the helper source.
+    ///
+    /// Each helper is parsed once and cloned after that. The sources are
+    /// fixed at build time and `tests/helpers.rs` checks that they parse, so
+    /// a failure is reported as an error rather than a panic.
+    fn declaration(self) -> Vec<Stmt> {
+        static VALIDATE_INPUT: OnceLock<Result<Vec<Stmt>, String>> = OnceLock::new();
+        static NORMALIZE_RESULT: OnceLock<Result<Vec<Stmt>, String>> = OnceLock::new();
+        static EXECUTE: OnceLock<Result<Vec<Stmt>, String>> = OnceLock::new();
+
+        let cache = match self {
+            Helper::ValidateInput => &VALIDATE_INPUT,
+            Helper::NormalizeResult => &NORMALIZE_RESULT,
+            Helper::Execute => &EXECUTE,
+        };
+        match cache.get_or_init(|| self.parse()) {
+            Ok(body) => body.clone(),
+            Err(message) => {
+                HANDLER.with(|handler| handler.err(message));
+                Vec::new()
+            }
+        }
+    }
+
+    fn parse(self) -> Result<Vec<Stmt>, String> {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(Lrc::new(FileName::Anon), self.source().to_string());
+        let mut errors = Vec::new();
+        let script = parse_file_as_script(
+            &fm,
+            Syntax::Es(Default::default()),
+            EsVersion::latest(),
+            None,
+            &mut errors,
+        );
+        match script {
+            Ok(mut script) if errors.is_empty() => {
+                script.visit_mut_with(&mut DropSpans);
+                Ok(script.body)
+            }
+            Ok(_) | Err(_) => Err(format!(
+                "Runner helper {} could not be parsed; this is a bug in the plugin",
+                self.name()
+            )),
+        }
+    }
+}
+
+/// Resets spans that point into a helper's own source file.
+struct DropSpans;
+
+impl VisitMut for DropSpans {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        *span = DUMMY_SP;
+    }
+}
+
+/// How one runner is rewritten.
+struct RunnerWrap {
+    runner_id: String,
+    /// Standard Schema the input is validated against before the body runs
+    input_schema: Option<String>,
+    /// Time the runner and turn what it throws into an error result
+    normalize_result: bool,
+    /// In client mode, the URL the stub replacing the body posts to
+    execute_url: Option<String>,
+    /// Name the server looks the runner up by, as in a `/execute` request
+    name: String,
+}
+
+impl RunnerWrap {
+    fn is_noop(&self) -> bool {
+        self.input_schema.is_none() && !self.normalize_result && self.execute_url.is_none()
+    }
+}
+
+/// The schema a runner's input is validated against: the one named by its
+/// `Runner<typeof Schema>` annotation, or else a `<name>InputSchema` variable
+/// that can be referred to from module scope.
+pub(crate) fn input_schema(site: &RunnerSite, variables: &HashSet<String>) -> Option<String> {
+    if let Some(schema) = &site.annotated_schema {
+        return Some(schema.clone());
+    }
+
+    let schema = format!("{}InputSchema", site.name);
+    variables.contains(&schema).then_some(schema)
+}
+
+/// `a` or `a.b.c` as an expression.
+pub(crate) fn path_expr(path: &str) -> Box<Expr> {
+    let mut segments = path.split('.');
+    let mut expr = Box::new(Expr::Ident(Ident::from(segments.next().unwrap_or_default())));
+    for segment in segments {
+        expr = Box::new(Expr::Member(MemberExpr {
+            obj: expr,
+            prop: MemberProp::Ident(IdentName::from(segment)),
+            ..Default::default()
+        }));
+    }
+    expr
+}
+
+pub(crate) fn str_lit(value: &str) -> Box<Expr> {
+    Box::new(Expr::Lit(Lit::Str(Str::from(value))))
+}
+
+pub(crate) fn num_lit(value: usize) -> Box<Expr> {
+    Box::new(Expr::Lit(Lit::Num(Number {
+        span: DUMMY_SP,
+        value: value as f64,
+        raw: None,
+    })))
+}
+
+pub(crate) fn prop(key: PropName, value: Box<Expr>) -> PropOrSpread {
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp { key, value })))
+}
+
+pub(crate) fn field(key: &str, value: Box<Expr>) -> PropOrSpread {
+    prop(PropName::Ident(IdentName::from(key)), value)
+}
+
+pub(crate) fn object(props: Vec<PropOrSpread>) -> Box<Expr> {
+    Box::new(Expr::Object(ObjectLit {
+        span: DUMMY_SP,
+        props,
+    }))
+}
+
+pub(crate) fn arg(expr: Box<Expr>) -> ExprOrSpread {
+    ExprOrSpread { spread: None, expr }
+}
+
+pub(crate) fn call(callee: Box<Expr>, args: Vec<ExprOrSpread>) -> Box<Expr> {
+    Box::new(Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..Default::default()
+    }))
+}
+
+/// `run(...__runnerArgs)`
+fn call_with_args(run: Box<Expr>) -> Box<Expr> {
+    call(
+        run,
+        vec![ExprOrSpread {
+            spread: Some(DUMMY_SP),
+            expr: Box::new(Expr::Ident(Ident::from(ARGS))),
+        }],
+    )
+}
+
+/// `...__runnerArgs`
+fn rest_args() -> Pat {
+    Pat::Rest(RestPat {
+        span: DUMMY_SP,
+        dot3_token: DUMMY_SP,
+        arg: Box::new(Pat::Ident(Ident::from(ARGS).into())),
+        type_ann: None,
+    })
+}
+
+pub(crate) struct RunnerRewriter {
+    /// Runners to rewrite, keyed by the start of their function's span
+    runners: HashMap<BytePos, RunnerWrap>,
+    /// Helpers called by the rewritten runners
+    helpers: BTreeSet<Helper>,
+}
+
+impl RunnerRewriter {
+    pub fn new(scan: &FileScan, config: &WasmConfig) -> Self {
+        let mut runners = HashMap::new();
+        for site in &scan.runners {
+            // A function exported under several names is rewritten once,
+            // under the first of them
+            runners.entry(site.span.lo).or_insert_with(|| {
+                let runner_id = runners_model::runner_id(&scan.metadata.file, &site.name);
+                match config.mode {
+                    // The body runs on the server, which validates and normalizes
+                    TransformMode::Client => RunnerWrap {
+                        runner_id,
+                        input_schema: None,
+                        normalize_result: false,
+                        execute_url: Some(config.execute_url.clone()),
+                        name: site.name.clone(),
+                    },
+                    TransformMode::Server => RunnerWrap {
+                        runner_id,
+                        input_schema: config
+                            .validate_input
+                            .then(|| input_schema(site, &scan.variables))
+                            .flatten(),
+                        normalize_result: config.normalize_results,
+                        execute_url: None,
+                        name: site.name.clone(),
+                    },
+                }
+            });
+        }
+        runners.retain(|_, wrap| !wrap.is_noop());
+
+        Self {
+            runners,
+            helpers: BTreeSet::new(),
+        }
+    }
+
+    /// Declarations of the helpers the rewritten runners call, to be appended
+    /// to the file.
+    pub fn helper_declarations(&self) -> Vec<Stmt> {
+        self.helpers
+            .iter()
+            .flat_map(|helper| helper.declaration())
+            .collect()
+    }
+
+    fn call_helper(&mut self, helper: Helper, args: Vec<ExprOrSpread>) -> Box<Expr> {
+        self.helpers.insert(helper);
+        call(Box::new(Expr::Ident(Ident::from(helper.name()))), args)
+    }
+
+    /// The expression that replaces the body of a runner: its original
+    /// parameters and body as an async arrow, wrapped in the helpers and
+    /// called with `...__runnerArgs`. In client mode the parameters and body
+    /// are dropped for a stub that calls the runner over HTTP.
+    fn wrapped_call(
+        &mut self,
+        wrap: &RunnerWrap,
+        params: Vec<Pat>,
+        body: Box<BlockStmtOrExpr>,
+    ) -> Box<Expr> {
+        if let Some(url) = &wrap.execute_url {
+            let stub = self.call_helper(
+                Helper::Execute,
+                vec![arg(str_lit(url)), arg(str_lit(&wrap.name))],
+            );
+            return call_with_args(stub);
+        }
+
+        let mut run = Box::new(Expr::Arrow(ArrowExpr {
+            params,
+            body,
+            is_async: true,
+            ..Default::default()
+        }));
+
+        if let Some(schema) = &wrap.input_schema {
+            run = self.call_helper(
+                Helper::ValidateInput,
+                vec![arg(str_lit(&wrap.runner_id)), arg(path_expr(schema)), arg(run)],
+            );
+        }
+        // Outermost, so failed validation is timed like any other result
+        if wrap.normalize_result {
+            run = self.call_helper(
+                Helper::NormalizeResult,
+                vec![arg(str_lit(&wrap.runner_id)), arg(run)],
+            );
+        }
+
+        call_with_args(run)
+    }
+}
+
+impl VisitMut for RunnerRewriter {
+    fn visit_mut_function(&mut self, function: &mut Function) {
+        function.visit_mut_children_with(self);
+
+        if function.is_generator {
+            return;
+        }
+        let Some(wrap) = self.runners.remove(&function.span.lo) else {
+            return;
+        };
+        let Some(body) = function.body.take() else {
+            return;
+        };
+
+        let params = std::mem::replace(&mut function.params, vec![Param::from(rest_args())])
+            .into_iter()
+            .map(|param| param.pat)
+            .collect();
+        let call = self.wrapped_call(&wrap, params, Box::new(BlockStmtOrExpr::BlockStmt(body)));
+        function.body = Some(BlockStmt {
+            stmts: vec![Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(call),
+            })],
+            ..Default::default()
+        });
+    }
+
+    fn visit_mut_arrow_expr(&mut self, arrow: &mut ArrowExpr) {
+        arrow.visit_mut_children_with(self);
+
+        let Some(wrap) = self.runners.remove(&arrow.span.lo) else {
+            return;
+        };
+
+        let params = std::mem::replace(&mut arrow.params, vec![rest_args()]);
+        let body = std::mem::replace(&mut *arrow.body, BlockStmtOrExpr::BlockStmt(Default::default()));
+        *arrow.body = BlockStmtOrExpr::Expr(self.wrapped_call(&wrap, params, Box::new(body)));
+    }
+}
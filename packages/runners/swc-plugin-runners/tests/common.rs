@@ -1,11 +1,39 @@
 //! Common test utilities for swc-plugin-runners tests
 
-use std::path::Path;
-use swc_core::ecma::{
-    transforms::testing::{FixtureTestConfig, test_fixture},
-    visit::visit_mut_pass,
+// Each test binary uses only some of these
+#![allow(dead_code)]
+
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+use swc_core::{
+    common::{
+        errors::{DiagnosticBuilder, DiagnosticId, Emitter},
+        BytePos,
+    },
+    ecma::{
+        parser::{Syntax, TsSyntax},
+        transforms::testing::{FixtureTestConfig, test_fixture},
+        visit::visit_mut_pass,
+    },
 };
-use swc_plugin_runners::RunnerTransform;
+use swc_plugin_runners::{RunnerTransform, WasmConfig};
+
+/// Whether a fixture input is a CommonJS script (`input.cjs`).
+pub fn is_commonjs(input: &Path) -> bool {
+    input.extension().is_some_and(|extension| extension == "cjs")
+}
+
+/// Syntax to parse a fixture input with: TypeScript for `input.ts`, so type
+/// annotations reach the plugin as they do before SWC strips them.
+pub fn syntax_of(input: &Path) -> Syntax {
+    if input.extension().is_some_and(|extension| extension == "ts") {
+        Syntax::Typescript(TsSyntax::default())
+    } else {
+        Syntax::default()
+    }
+}
 
 /// Runs a fixture test with the given configuration.
 ///
@@ -22,12 +50,57 @@ pub fn run_fixture_test(
         .to_string_lossy()
         .to_string();
 
+    // Optional plugin options live next to the input as `config.json`
+    let config_path = input.with_file_name("config.json");
+    let plugin_config: WasmConfig = if config_path.exists() {
+        let json = std::fs::read_to_string(&config_path).expect("config.json should be readable");
+        serde_json::from_str(&json).expect("config.json should be a valid plugin config")
+    } else {
+        WasmConfig::default()
+    };
+
     test_fixture(
-        Default::default(),
-        &|_| visit_mut_pass(RunnerTransform::new(filename.clone())),
+        syntax_of(input),
+        &|tester| {
+            visit_mut_pass(
+                RunnerTransform::new(filename.clone())
+                    .with_config(plugin_config.clone())
+                    .with_comments(tester.comments.clone())
+                    .with_source_map(tester.cm.clone()),
+            )
+        },
         input,
         output,
         config,
     );
 }
 
+/// Code and position of an error emitted by the plugin.
+pub type EmittedError = (String, Option<BytePos>);
+
+/// Records every error the plugin emits.
+#[derive(Clone, Default)]
+pub struct CapturingEmitter(Arc<Mutex<Vec<EmittedError>>>);
+
+impl CapturingEmitter {
+    /// The errors recorded so far.
+    pub fn errors(&self) -> Vec<EmittedError> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// The codes of the errors recorded so far.
+    pub fn codes(&self) -> Vec<String> {
+        self.errors().into_iter().map(|(code, _)| code).collect()
+    }
+}
+
+impl Emitter for CapturingEmitter {
+    fn emit(&mut self, db: &mut DiagnosticBuilder<'_>) {
+        let code = match &db.code {
+            Some(DiagnosticId::Error(code) | DiagnosticId::Lint(code)) => code.clone(),
+            None => db.message(),
+        };
+        let pos = db.span.primary_span().map(|span| span.lo);
+        self.0.lock().unwrap().push((code, pos));
+    }
+}
@@ -0,0 +1,217 @@
+//! Cross-tool conformance: runs sources through both the SWC plugin and
+//! `schema_extractor`, which implement the same rules from `runners_model`.
+//!
+//! Cases in `../conformance/<case>/` pin the transform output
+//! (`output.ts`/`output.tsx`), the metadata both tools must agree on
+//! (`metadata.json`) and each tool's diagnostics (`diagnostics.json`). Run
+//! `UPDATE=1 cargo test --test conformance` to regenerate them.
+
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use swc_core::{
+    common::errors::{Handler, HANDLER},
+    ecma::{
+        ast::{Pass, Program},
+        parser::{Syntax, TsSyntax},
+        transforms::testing::Tester,
+        visit::visit_mut_pass,
+    },
+};
+use swc_plugin_runners::{
+    collect_metadata, collect_script_metadata, RunnerTransform, SchemaMetadata, WasmConfig,
+};
+use testing::NormalizedOutput;
+
+mod common;
+use common::CapturingEmitter;
+
+fn runner_factories() -> Vec<String> {
+    WasmConfig::default().runner_factories
+}
+
+#[testing::fixture("tests/fixture/**/input.js")]
+#[testing::fixture("tests/fixture/**/input.cjs")]
+#[testing::fixture("tests/fixture/**/input.ts")]
+#[testing::fixture("tests/errors/**/input.js")]
+#[testing::fixture("tests/errors/**/input.cjs")]
+fn conformance_test(input: PathBuf) {
+    let source = fs::read_to_string(&input).expect("input should be readable");
+    let file = input.file_name().unwrap().to_string_lossy().to_string();
+    let file = file.as_str();
+
+    let plugin = Tester::run(|tester| {
+        let cm = Some(&(tester.cm.clone() as _));
+        // CommonJS inputs are scripts, as in the fixture tests
+        if input.extension().is_some_and(|extension| extension == "cjs") {
+            let syntax = Syntax::Es(Default::default());
+            let script = tester.with_parser(file, syntax, &source, |parser| parser.parse_script())?;
+            Ok(collect_script_metadata(&script, file, &runner_factories(), cm))
+        } else if input.extension().is_some_and(|extension| extension == "ts") {
+            let syntax = Syntax::Typescript(TsSyntax::default());
+            let module = tester.with_parser(file, syntax, &source, |parser| parser.parse_module())?;
+            Ok(collect_metadata(&module, file, &runner_factories(), cm))
+        } else {
+            let module = tester.parse_module(file, &source)?;
+            Ok(collect_metadata(&module, file, &runner_factories(), cm))
+        }
+    });
+
+    let mut diagnostics = Vec::new();
+    let extractor = schema_extractor::extract_file_with_diagnostics(
+        &source,
+        Path::new(file),
+        &runner_factories(),
+        runners_model::DEFAULT_TYPO_THRESHOLD,
+        &mut diagnostics,
+    );
+
+    assert_eq!(plugin.runners, extractor.runners, "runners differ for {}", input.display());
+    assert_eq!(plugin.schemas, extractor.schemas, "schemas differ for {}", input.display());
+
+    // The plugin reports typos as errors; the extractor as warnings
+    let stderr = input.with_file_name("output.stderr");
+    let plugin_typo = fs::read_to_string(stderr).is_ok_and(|s| s.contains("looks like a typo"));
+    let extractor_typo = diagnostics
+        .iter()
+        .any(|d| d.code == schema_extractor::DiagnosticCode::MisspelledDirective);
+    assert_eq!(plugin_typo, extractor_typo, "typo detection differs for {}", input.display());
+}
+
+#[testing::fixture("../conformance/**/input.ts")]
+#[testing::fixture("../conformance/**/input.tsx")]
+fn corpus_test(input: PathBuf) {
+    let dir = input.parent().expect("input should have a parent directory");
+    let file = input.file_name().unwrap().to_string_lossy().to_string();
+    let extension = input.extension().unwrap().to_string_lossy().to_string();
+    let source = fs::read_to_string(&input).expect("input should be readable");
+
+    let plugin = run_plugin(&file, &source, extension == "tsx");
+
+    let mut diagnostics = Vec::new();
+    let extractor = schema_extractor::extract_file_with_diagnostics(
+        &source,
+        Path::new(&file),
+        &runner_factories(),
+        runners_model::DEFAULT_TYPO_THRESHOLD,
+        &mut diagnostics,
+    );
+
+    assert_eq!(plugin.metadata.runners, extractor.runners, "runners differ for {}", input.display());
+    assert_eq!(plugin.metadata.schemas, extractor.schemas, "schemas differ for {}", input.display());
+
+    NormalizedOutput::from(plugin.code)
+        .compare_to_file(dir.join(format!("output.{extension}")))
+        .unwrap();
+
+    NormalizedOutput::from(to_json(&extractor))
+        .compare_to_file(dir.join("metadata.json"))
+        .unwrap();
+
+    let expected_diagnostics = ExpectedDiagnostics {
+        plugin: plugin.diagnostics,
+        extractor: diagnostics
+            .iter()
+            .map(|diagnostic| ExpectedDiagnostic {
+                code: diagnostic.code.as_str().to_string(),
+                severity: Some(
+                    serde_json::to_value(diagnostic.severity)
+                        .unwrap()
+                        .as_str()
+                        .unwrap()
+                        .to_string(),
+                ),
+                line: diagnostic.range.map_or(0, |range| range.start.line),
+            })
+            .collect(),
+    };
+    NormalizedOutput::from(to_json(&expected_diagnostics))
+        .compare_to_file(dir.join("diagnostics.json"))
+        .unwrap();
+
+    // Project scans skip files without a directive, which must not hide a
+    // misspelled one
+    let project = schema_extractor::extract_project(&schema_extractor::ExtractOptions {
+        root: dir.to_path_buf(),
+        patterns: vec![file.clone()],
+        ..Default::default()
+    })
+    .expect("case directory should be scannable");
+    let typo_lines = |diagnostics: &[schema_extractor::Diagnostic]| {
+        diagnostics
+            .iter()
+            .filter(|d| d.code == schema_extractor::DiagnosticCode::MisspelledDirective)
+            .map(|d| d.range.map_or(0, |range| range.start.line))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        typo_lines(&project.diagnostics),
+        typo_lines(&diagnostics),
+        "project scan typos differ for {}",
+        input.display()
+    );
+}
+
+struct PluginRun {
+    code: String,
+    metadata: SchemaMetadata,
+    diagnostics: Vec<ExpectedDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct ExpectedDiagnostics {
+    plugin: Vec<ExpectedDiagnostic>,
+    extractor: Vec<ExpectedDiagnostic>,
+}
+
+#[derive(Serialize)]
+struct ExpectedDiagnostic {
+    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<String>,
+    line: usize,
+}
+
+fn run_plugin(file: &str, source: &str, tsx: bool) -> PluginRun {
+    let syntax = Syntax::Typescript(TsSyntax {
+        tsx,
+        ..Default::default()
+    });
+
+    Tester::run(|tester| {
+        let module = tester.with_parser(file, syntax, source, |parser| parser.parse_module())?;
+        let cm = tester.cm.clone();
+        let metadata =
+            collect_metadata(&module, file, &runner_factories(), Some(&(cm.clone() as _)));
+
+        let emitter = CapturingEmitter::default();
+        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
+        let mut pass = visit_mut_pass(
+            RunnerTransform::new(file.to_string()).with_comments(tester.comments.clone()),
+        );
+        let mut program = Program::Module(module);
+        HANDLER.set(&handler, || pass.process(&mut program));
+
+        let diagnostics = emitter
+            .errors()
+            .into_iter()
+            .map(|(code, pos)| ExpectedDiagnostic {
+                code,
+                severity: None,
+                line: pos.map_or(0, |pos| cm.lookup_char_pos(pos).line),
+            })
+            .collect();
+
+        Ok(PluginRun {
+            code: tester.print(&program, &tester.comments.clone()),
+            metadata,
+            diagnostics,
+        })
+    })
+}
+
+fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap() + "\n"
+}
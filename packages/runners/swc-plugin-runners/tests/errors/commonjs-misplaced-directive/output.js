@@ -0,0 +1,8 @@
+/*__runners_transformed*/ const { z } = require("zod");
+"use runner";
+exports.fetchUser = async (ctx)=>{
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+};
@@ -2,6 +2,6 @@
 /** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */
 export async function myRunner(_ctx) {
   const _x = 1;
-  ("use runner");
+  "use runner";
   return { name: "test", status: "pass" };
 }
@@ -1,5 +1,8 @@
-/** biome-ignore-all lint/suspicious/useAwait: false positive */
-export async function myRunner(_ctx) {
-  const _x = 1;
-  return { name: "test", status: "pass" };
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */ /*__runners_transformed*/ export async function myRunner(_ctx) {
+    const _x = 1;
+    "use runner";
+    return {
+        name: "test",
+        status: "pass"
+    };
 }
@@ -1,4 +1,6 @@
 /** biome-ignore-all lint/suspicious/useAwait: false positive */
+/** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */
+const _timeout = 1000;
 "use runner";
 
 export async function myRunner(_ctx) {
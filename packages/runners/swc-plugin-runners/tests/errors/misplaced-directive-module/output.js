@@ -1,4 +1,8 @@
-/** biome-ignore-all lint/suspicious/useAwait: false positive */
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */ /*__runners_transformed*/ const _timeout = 1000;
+"use runner";
 export async function myRunner(_ctx) {
-  return { name: "test", status: "pass" };
+    return {
+        name: "test",
+        status: "pass"
+    };
 }
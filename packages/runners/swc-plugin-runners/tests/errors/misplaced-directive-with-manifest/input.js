@@ -0,0 +1,7 @@
+"use runner";
+
+export async function fetchUser(ctx) {
+  const user = await ctx.load();
+  "use runner";
+  return { name: "fetchUser", status: "pass", user };
+}
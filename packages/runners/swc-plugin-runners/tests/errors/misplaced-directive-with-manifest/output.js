@@ -0,0 +1,9 @@
+/**__internal_runners{"runners":{"input.js":{"fetchUser":{"runnerId":"input.js#fetchUser"}}},"metadata":{"file":"input.js","runners":[{"name":"fetchUser","line":3}],"schemas":[],"partial":false}}*/ export async function fetchUser(ctx) {
+    const user = await ctx.load();
+    "use runner";
+    return {
+        name: "fetchUser",
+        status: "pass",
+        user
+    };
+}
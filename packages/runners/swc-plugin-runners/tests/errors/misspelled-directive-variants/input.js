@@ -0,0 +1,26 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+export async function hyphenated(_ctx) {
+  "use-runner";
+  return { name: "hyphenated", status: "pass" };
+}
+
+export async function shouting(_ctx) {
+  "USE  RUNNER";
+  return { name: "shouting", status: "pass" };
+}
+
+export async function transposed(_ctx) {
+  "use rnuner";
+  return { name: "transposed", status: "pass" };
+}
+
+// Other frameworks' directives, and typos of them, are left alone
+export async function serverAction() {
+  "use server";
+  return 1;
+}
+
+export async function serverTypo() {
+  "use servr";
+  return 2;
+}
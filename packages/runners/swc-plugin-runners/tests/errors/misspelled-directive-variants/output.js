@@ -0,0 +1,27 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function hyphenated(_ctx) {
+    return {
+        name: "hyphenated",
+        status: "pass"
+    };
+}
+export async function shouting(_ctx) {
+    return {
+        name: "shouting",
+        status: "pass"
+    };
+}
+export async function transposed(_ctx) {
+    return {
+        name: "transposed",
+        status: "pass"
+    };
+}
+// Other frameworks' directives, and typos of them, are left alone
+export async function serverAction() {
+    "use server";
+    return 1;
+}
+export async function serverTypo() {
+    "use servr";
+    return 2;
+}
@@ -0,0 +1,12 @@
+export class Suite {
+  constructor() {
+    "use strict";
+    "use runner";
+    this.ready = true;
+  }
+
+  get name() {
+    "use runner";
+    return "suite";
+  }
+}
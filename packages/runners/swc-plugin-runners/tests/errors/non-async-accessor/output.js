@@ -0,0 +1,9 @@
+/*__runners_transformed*/ export class Suite {
+    constructor(){
+        "use strict";
+        this.ready = true;
+    }
+    get name() {
+        return "suite";
+    }
+}
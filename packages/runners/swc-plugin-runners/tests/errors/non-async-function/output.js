@@ -1,3 +1,6 @@
-export function badRunner(_ctx) {
-  return { name: "test", status: "pass" };
+/*__runners_transformed*/ export function badRunner(_ctx) {
+    return {
+        name: "test",
+        status: "pass"
+    };
 }
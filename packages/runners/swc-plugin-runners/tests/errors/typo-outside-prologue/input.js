@@ -0,0 +1,11 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+/** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */
+export const timeout = 1000;
+"use runnr";
+
+export async function myRunner(_ctx) {
+  "use runner";
+  const label = "checkout";
+  "use runnes";
+  return { name: label, status: "pass" };
+}
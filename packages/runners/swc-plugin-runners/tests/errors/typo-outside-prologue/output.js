@@ -0,0 +1,10 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /** biome-ignore-all lint/nursery/noUnusedExpressions: false positive */ /*__runners_transformed*/ export const timeout = 1000;
+"use runnr";
+export async function myRunner(_ctx) {
+    const label = "checkout";
+    "use runnes";
+    return {
+        name: label,
+        status: "pass"
+    };
+}
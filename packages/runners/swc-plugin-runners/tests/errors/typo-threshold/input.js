@@ -0,0 +1,10 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+export async function oneEditAway(_ctx) {
+  "use runer";
+  return { name: "oneEditAway", status: "pass" };
+}
+
+export async function hyphenated(_ctx) {
+  "Use-Runner";
+  return { name: "hyphenated", status: "pass" };
+}
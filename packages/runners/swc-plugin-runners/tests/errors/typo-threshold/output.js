@@ -0,0 +1,13 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function oneEditAway(_ctx) {
+    "use runer";
+    return {
+        name: "oneEditAway",
+        status: "pass"
+    };
+}
+export async function hyphenated(_ctx) {
+    return {
+        name: "hyphenated",
+        status: "pass"
+    };
+}
@@ -2,22 +2,40 @@ use std::path::PathBuf;
 use swc_core::ecma::transforms::testing::FixtureTestConfig;
 
 mod common;
-use common::run_fixture_test;
+use common::{is_commonjs, run_fixture_test};
 
 #[testing::fixture("tests/fixture/**/input.js")]
+#[testing::fixture("tests/fixture/**/input.cjs")]
+#[testing::fixture("tests/fixture/**/input.ts")]
 fn fixture_test(input: PathBuf) {
-    let output = input
-        .parent()
-        .expect("input file should have a parent directory")
-        .join("output.js");
+    // TypeScript inputs keep their types, so their output is TypeScript too
+    let output = if input.extension().is_some_and(|extension| extension == "ts") {
+        input.with_file_name("output.ts")
+    } else {
+        input.with_file_name("output.js")
+    };
 
     run_fixture_test(
         &input,
         &output,
         FixtureTestConfig {
-            module: Some(true),
+            // CommonJS inputs are parsed as scripts
+            module: Some(!is_commonjs(&input)),
             ..Default::default()
         },
     );
 }
 
+/// `@swc/core` parses `.cjs` files as modules unless told otherwise; the
+/// plugin must still treat them as the CommonJS scripts they are.
+#[testing::fixture("tests/fixture/**/input.cjs")]
+fn commonjs_parsed_as_module_test(input: PathBuf) {
+    run_fixture_test(
+        &input,
+        &input.with_file_name("output.js"),
+        FixtureTestConfig {
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
@@ -0,0 +1,13 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+"use strict";
+"use runner";
+
+export async function strictModule(_ctx) {
+  return { name: "strictModule", status: "pass" };
+}
+
+export async function strictFunction(_ctx) {
+  "use strict";
+  "use runner";
+  return { name: "strictFunction", status: "pass" };
+}
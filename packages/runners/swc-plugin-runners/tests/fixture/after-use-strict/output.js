@@ -0,0 +1,14 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ "use strict";
+export async function strictModule(_ctx) {
+    return {
+        name: "strictModule",
+        status: "pass"
+    };
+}
+export async function strictFunction(_ctx) {
+    "use strict";
+    return {
+        name: "strictFunction",
+        status: "pass"
+    };
+}
@@ -1 +1,6 @@
-export const myRunner = async (_ctx) => ({ name: "test", status: "pass" });
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export const myRunner = async (_ctx)=>{
+    return {
+        name: "test",
+        status: "pass"
+    };
+};
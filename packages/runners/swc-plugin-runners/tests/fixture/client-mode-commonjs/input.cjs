@@ -0,0 +1,14 @@
+"use runner";
+const { chromium } = require("playwright");
+const { z } = require("zod");
+const retry = require("./retry");
+
+exports.checkTitleSchema = z.object({ url: z.string() });
+
+exports.checkTitle = async (ctx, input) => {
+  return retry(async () => {
+    const browser = await chromium.launch();
+    await browser.close();
+    return { name: "checkTitle", status: "pass" };
+  });
+};
@@ -0,0 +1,54 @@
+/*__runners_transformed*/ const { z } = require("zod");
+exports.checkTitleSchema = z.object({
+    url: z.string()
+});
+exports.checkTitle = async (...__runnerArgs)=>__runners_execute("/api/runner/execute", "checkTitle")(...__runnerArgs);
+function __runners_execute(endpoint, name) {
+    return async (ctx, input)=>{
+        let url;
+        try {
+            url = new URL(endpoint, globalThis.location?.href);
+        } catch  {
+            return {
+                name,
+                status: "error",
+                errorMessage: `executeUrl must be an absolute URL outside a browser, got "${endpoint}"`
+            };
+        }
+        try {
+            const response = await fetch(url, {
+                method: "POST",
+                headers: {
+                    "content-type": "application/json"
+                },
+                body: JSON.stringify({
+                    runners: [
+                        {
+                            name,
+                            input
+                        }
+                    ]
+                })
+            });
+            if (!response.ok) {
+                return {
+                    name,
+                    status: "error",
+                    errorMessage: `Runner request failed with status ${response.status}`
+                };
+            }
+            const { results } = await response.json();
+            return results?.[0] ?? {
+                name,
+                status: "error",
+                errorMessage: "Runner response contained no result"
+            };
+        } catch (error) {
+            return {
+                name,
+                status: "error",
+                errorMessage: error instanceof Error ? error.message : String(error)
+            };
+        }
+    };
+}
@@ -0,0 +1,19 @@
+"use runner";
+
+import "./polyfills";
+import { chromium } from "playwright";
+import { z } from "zod";
+import { formatTitle, TITLE_LIMIT } from "./format";
+
+export const checkTitleSchema = z.object({ url: z.string(), limit: z.number().default(TITLE_LIMIT) });
+
+export async function checkTitle(ctx, { url }) {
+  const browser = await chromium.launch();
+  const page = await browser.newPage();
+  await page.goto(url);
+  const title = formatTitle(await page.title());
+  await browser.close();
+  return { name: "checkTitle", status: title ? "pass" : "fail" };
+}
+
+export const ping = async (ctx) => ({ name: "ping", status: "pass" });
@@ -0,0 +1,60 @@
+/*__runners_transformed*/ import "./polyfills";
+import { z } from "zod";
+import { TITLE_LIMIT } from "./format";
+export const checkTitleSchema = z.object({
+    url: z.string(),
+    limit: z.number().default(TITLE_LIMIT)
+});
+export async function checkTitle(...__runnerArgs) {
+    return __runners_execute("https://runners.example.com/api/runner/execute", "checkTitle")(...__runnerArgs);
+}
+export const ping = async (...__runnerArgs)=>__runners_execute("https://runners.example.com/api/runner/execute", "ping")(...__runnerArgs);
+function __runners_execute(endpoint, name) {
+    return async (ctx, input)=>{
+        let url;
+        try {
+            url = new URL(endpoint, globalThis.location?.href);
+        } catch  {
+            return {
+                name,
+                status: "error",
+                errorMessage: `executeUrl must be an absolute URL outside a browser, got "${endpoint}"`
+            };
+        }
+        try {
+            const response = await fetch(url, {
+                method: "POST",
+                headers: {
+                    "content-type": "application/json"
+                },
+                body: JSON.stringify({
+                    runners: [
+                        {
+                            name,
+                            input
+                        }
+                    ]
+                })
+            });
+            if (!response.ok) {
+                return {
+                    name,
+                    status: "error",
+                    errorMessage: `Runner request failed with status ${response.status}`
+                };
+            }
+            const { results } = await response.json();
+            return results?.[0] ?? {
+                name,
+                status: "error",
+                errorMessage: "Runner response contained no result"
+            };
+        } catch (error) {
+            return {
+                name,
+                status: "error",
+                errorMessage: error instanceof Error ? error.message : String(error)
+            };
+        }
+    };
+}
@@ -0,0 +1,21 @@
+async function checkLinks(ctx) {
+  "use runner";
+  return { name: "checkLinks", status: "pass" };
+}
+
+async function notARunner(ctx) {
+  return { name: "notARunner", status: "pass" };
+}
+
+module.exports = {
+  checkLinks,
+  notARunner,
+  checkImages: async (ctx) => {
+    "use runner";
+    return { name: "checkImages", status: "pass" };
+  },
+  async checkMeta(ctx) {
+    "use runner";
+    return { name: "checkMeta", status: "pass" };
+  },
+};
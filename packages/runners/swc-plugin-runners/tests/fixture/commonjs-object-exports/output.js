@@ -0,0 +1,28 @@
+/**__internal_runners{"runners":{"input.cjs":{"checkImages":{"runnerId":"input.cjs#checkImages"},"checkLinks":{"runnerId":"input.cjs#checkLinks"},"checkMeta":{"runnerId":"input.cjs#checkMeta"}}},"metadata":{"file":"input.cjs","runners":[{"name":"checkLinks","line":1},{"name":"checkImages","line":13},{"name":"checkMeta","line":17}],"schemas":[],"partial":false}}*/ async function checkLinks(ctx) {
+    return {
+        name: "checkLinks",
+        status: "pass"
+    };
+}
+async function notARunner(ctx) {
+    return {
+        name: "notARunner",
+        status: "pass"
+    };
+}
+module.exports = {
+    checkLinks,
+    notARunner,
+    checkImages: async (ctx)=>{
+        return {
+            name: "checkImages",
+            status: "pass"
+        };
+    },
+    async checkMeta (ctx) {
+        return {
+            name: "checkMeta",
+            status: "pass"
+        };
+    }
+};
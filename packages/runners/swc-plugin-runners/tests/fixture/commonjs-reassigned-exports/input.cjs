@@ -0,0 +1,23 @@
+"use runner";
+
+// Replaced by the `module.exports = { ... }` below, so not exported
+exports.dropped = async (ctx) => {
+  return { name: "dropped", status: "pass" };
+};
+
+async function checkLinks(ctx) {
+  return { name: "checkLinks", status: "pass" };
+}
+
+module.exports = {
+  checkLinks,
+};
+
+module.exports.checkMeta = async (ctx) => {
+  return { name: "checkMeta", status: "pass" };
+};
+
+// `exports` still refers to the replaced object
+exports.alsoDropped = async (ctx) => {
+  return { name: "alsoDropped", status: "pass" };
+};
@@ -0,0 +1,29 @@
+/**__internal_runners{"runners":{"input.cjs":{"checkLinks":{"runnerId":"input.cjs#checkLinks"},"checkMeta":{"runnerId":"input.cjs#checkMeta"}}},"metadata":{"file":"input.cjs","runners":[{"name":"checkLinks","line":8},{"name":"checkMeta","line":16}],"schemas":[],"partial":false}}*/ // Replaced by the `module.exports = { ... }` below, so not exported
+exports.dropped = async (ctx)=>{
+    return {
+        name: "dropped",
+        status: "pass"
+    };
+};
+async function checkLinks(ctx) {
+    return {
+        name: "checkLinks",
+        status: "pass"
+    };
+}
+module.exports = {
+    checkLinks
+};
+module.exports.checkMeta = async (ctx)=>{
+    return {
+        name: "checkMeta",
+        status: "pass"
+    };
+};
+// `exports` still refers to the replaced object
+exports.alsoDropped = async (ctx)=>{
+    return {
+        name: "alsoDropped",
+        status: "pass"
+    };
+};
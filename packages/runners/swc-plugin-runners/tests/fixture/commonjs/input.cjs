@@ -0,0 +1,25 @@
+"use strict";
+"use runner";
+
+const { z } = require("zod");
+
+const fetchUserSchema = z.object({ id: z.string() });
+
+async function fetchUser(ctx, input) {
+  return { name: "fetchUser", status: "pass" };
+}
+
+exports.fetchUser = fetchUser;
+
+exports.checkTitle = async (ctx) => {
+  return { name: "checkTitle", status: "pass" };
+};
+
+module.exports.checkFooterSchema = z.object({ selector: z.string() });
+
+module.exports.checkFooter = async function (ctx) {
+  return { name: "checkFooter", status: "pass" };
+};
+
+// Not async, so not a runner
+exports.helper = (value) => value.trim();
@@ -0,0 +1,29 @@
+/**__internal_runners{"runners":{"input.cjs":{"checkFooter":{"runnerId":"input.cjs#checkFooter"},"checkTitle":{"runnerId":"input.cjs#checkTitle"},"fetchUser":{"runnerId":"input.cjs#fetchUser"}}},"metadata":{"file":"input.cjs","runners":[{"name":"fetchUser","line":8},{"name":"checkTitle","line":14},{"name":"checkFooter","line":20}],"schemas":[{"name":"checkFooterSchema","runner_name":"checkFooter","line":18}],"partial":false}}*/ "use strict";
+const { z } = require("zod");
+const fetchUserSchema = z.object({
+    id: z.string()
+});
+async function fetchUser(ctx, input) {
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+}
+exports.fetchUser = fetchUser;
+exports.checkTitle = async (ctx)=>{
+    return {
+        name: "checkTitle",
+        status: "pass"
+    };
+};
+module.exports.checkFooterSchema = z.object({
+    selector: z.string()
+});
+module.exports.checkFooter = async function(ctx) {
+    return {
+        name: "checkFooter",
+        status: "pass"
+    };
+};
+// Not async, so not a runner
+exports.helper = (value)=>value.trim();
@@ -0,0 +1,15 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+"use client";
+"use runner";
+"use strict";
+
+export async function moduleRunner(_ctx) {
+  return { name: "moduleRunner", status: "pass" };
+}
+
+export const arrowRunner = async (_ctx) => {
+  "use strict";
+  "use client";
+  "use runner";
+  return { name: "arrowRunner", status: "pass" };
+};
@@ -0,0 +1,16 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ "use client";
+"use strict";
+export async function moduleRunner(_ctx) {
+    return {
+        name: "moduleRunner",
+        status: "pass"
+    };
+}
+export const arrowRunner = async (_ctx)=>{
+    "use strict";
+    "use client";
+    return {
+        name: "arrowRunner",
+        status: "pass"
+    };
+};
@@ -0,0 +1,16 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+export async function escapedSpace(_ctx) {
+  "use\x20runner";
+  return { name: "escapedSpace", status: "pass" };
+}
+
+export async function unicodeEscape(_ctx) {
+  "use\u{20}runner";
+  return { name: "unicodeEscape", status: "pass" };
+}
+
+// Template literals are not directives and are left alone
+export async function templateLiteral(_ctx) {
+  `use runner`;
+  return { name: "templateLiteral", status: "pass" };
+}
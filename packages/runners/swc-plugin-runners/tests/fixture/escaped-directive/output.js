@@ -0,0 +1,20 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function escapedSpace(_ctx) {
+    return {
+        name: "escapedSpace",
+        status: "pass"
+    };
+}
+export async function unicodeEscape(_ctx) {
+    return {
+        name: "unicodeEscape",
+        status: "pass"
+    };
+}
+// Template literals are not directives and are left alone
+export async function templateLiteral(_ctx) {
+    `use runner`;
+    return {
+        name: "templateLiteral",
+        status: "pass"
+    };
+}
@@ -0,0 +1,19 @@
+"use runner";
+
+import { z } from "zod";
+
+export const fetchUserSchema = z.object({ id: z.string() });
+
+export async function fetchUser(ctx) {
+  return { name: "fetchUser", status: "pass" };
+}
+
+export const checkHeaders = async (ctx) => {
+  return { name: "checkHeaders", status: "pass" };
+};
+
+export const sharedSchema = z.object({});
+
+function helper() {
+  return 1;
+}
@@ -0,0 +1,20 @@
+/**__internal_runners{"runners":{"input.js":{"checkHeaders":{"runnerId":"input.js#checkHeaders"},"fetchUser":{"runnerId":"input.js#fetchUser"}}},"metadata":{"file":"input.js","runners":[{"name":"fetchUser","line":7},{"name":"checkHeaders","line":11}],"schemas":[{"name":"fetchUserSchema","runner_name":"fetchUser","line":5},{"name":"sharedSchema","runner_name":null,"line":15}],"partial":false}}*/ import { z } from "zod";
+export const fetchUserSchema = z.object({
+    id: z.string()
+});
+export async function fetchUser(ctx) {
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+}
+export const checkHeaders = async (ctx)=>{
+    return {
+        name: "checkHeaders",
+        status: "pass"
+    };
+};
+export const sharedSchema = z.object({});
+function helper() {
+    return 1;
+}
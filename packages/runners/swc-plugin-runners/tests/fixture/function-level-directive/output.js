@@ -1,4 +1,6 @@
-/** biome-ignore-all lint/suspicious/useAwait: false positive */
-export async function myRunner(_ctx) {
-  return { name: "test", status: "pass" };
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function myRunner(_ctx) {
+    return {
+        name: "test",
+        status: "pass"
+    };
 }
@@ -1,8 +1,12 @@
-/** biome-ignore-all lint/suspicious/useAwait: false positive */
-export async function runner1(_ctx) {
-  return { name: "test1", status: "pass" };
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function runner1(_ctx) {
+    return {
+        name: "test1",
+        status: "pass"
+    };
 }
-
 export async function runner2(_ctx) {
-  return { name: "test2", status: "pass" };
+    return {
+        name: "test2",
+        status: "pass"
+    };
 }
@@ -0,0 +1,21 @@
+import { z } from "zod";
+
+export const checkStatusInputSchema = z.object({ url: z.string() });
+
+export async function checkStatus(ctx, input) {
+  "use runner";
+  const response = await fetch(input.url);
+  if (!response.ok) {
+    throw new Error(`Unexpected status ${response.status}`);
+  }
+  return { status: "pass" };
+}
+
+export const ping = async (ctx) => {
+  "use runner";
+  return { name: "ping", status: "pass" };
+};
+
+export async function helper() {
+  return 1;
+}
@@ -0,0 +1,72 @@
+/*__runners_transformed*/ import { z } from "zod";
+export const checkStatusInputSchema = z.object({
+    url: z.string()
+});
+export async function checkStatus(...__runnerArgs) {
+    return __runners_normalizeResult("input.js#checkStatus", __runners_validateInput("input.js#checkStatus", checkStatusInputSchema, async (ctx, input)=>{
+        const response = await fetch(input.url);
+        if (!response.ok) {
+            throw new Error(`Unexpected status ${response.status}`);
+        }
+        return {
+            status: "pass"
+        };
+    }))(...__runnerArgs);
+}
+export const ping = async (...__runnerArgs)=>__runners_normalizeResult("input.js#ping", async (ctx)=>{
+        return {
+            name: "ping",
+            status: "pass"
+        };
+    })(...__runnerArgs);
+export async function helper() {
+    return 1;
+}
+function __runners_validateInput(runnerId, schema, run) {
+    return async (ctx, input, ...rest)=>{
+        if (typeof schema?.["~standard"]?.validate !== "function") {
+            return {
+                name: runnerId,
+                status: "error",
+                errorMessage: "Input schema is not a Standard Schema: it has no ~standard.validate function"
+            };
+        }
+        const result = await schema["~standard"].validate(input);
+        if (result.issues) {
+            const messages = result.issues.map((issue)=>issue.message).join(", ");
+            return {
+                name: runnerId,
+                status: "error",
+                errorMessage: `Validation failed: ${messages}`
+            };
+        }
+        return run(ctx, result.value, ...rest);
+    };
+}
+function __runners_normalizeResult(runnerId, run) {
+    return async (...args)=>{
+        const start = Date.now();
+        let result;
+        try {
+            result = await run(...args);
+        } catch (error) {
+            result = {
+                status: "error",
+                errorMessage: error instanceof Error ? error.message : String(error)
+            };
+        }
+        if (typeof result !== "object" || result === null) {
+            result = {
+                status: "pass",
+                details: {
+                    output: result
+                }
+            };
+        }
+        return {
+            ...result,
+            name: result.name ?? runnerId,
+            durationMs: result.durationMs ?? Date.now() - start
+        };
+    };
+}
@@ -0,0 +1,14 @@
+"use runner";
+const { z } = require("zod");
+
+async function fetchUser(ctx) {
+  return { name: "fetchUser", status: "pass" };
+}
+
+module.exports = {
+  fetchUser,
+  checkTitleSchema: z.object({ url: z.string() }),
+  async checkTitle(ctx, input) {
+    return { name: "checkTitle", status: "pass" };
+  },
+};
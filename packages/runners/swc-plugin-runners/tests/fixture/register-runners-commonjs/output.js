@@ -0,0 +1,33 @@
+/*__runners_transformed*/ const { z } = require("zod");
+async function fetchUser(ctx) {
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+}
+module.exports = {
+    fetchUser,
+    checkTitleSchema: z.object({
+        url: z.string()
+    }),
+    async checkTitle (ctx, input) {
+        return {
+            name: "checkTitle",
+            status: "pass"
+        };
+    }
+};
+module.exports.__runners = {
+    "input.cjs#fetchUser": {
+        fn: fetchUser,
+        name: "fetchUser",
+        file: "input.cjs",
+        line: 4
+    },
+    "input.cjs#checkTitle": {
+        fn: module.exports.checkTitle,
+        name: "checkTitle",
+        file: "input.cjs",
+        line: 11
+    }
+};
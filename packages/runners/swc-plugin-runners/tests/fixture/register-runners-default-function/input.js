@@ -0,0 +1,5 @@
+"use runner";
+
+export default async function (ctx) {
+  return { name: "smoke", status: "pass" };
+}
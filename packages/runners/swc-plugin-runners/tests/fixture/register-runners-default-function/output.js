@@ -0,0 +1,17 @@
+/*__runners_transformed*/ export default async function __runners_default(ctx) {
+    return {
+        name: "smoke",
+        status: "pass"
+    };
+}
+Object.defineProperty(__runners_default, "name", {
+    value: "default"
+});
+export const __runners = {
+    "input.js#default": {
+        fn: __runners_default,
+        name: "default",
+        file: "input.js",
+        line: 3
+    }
+};
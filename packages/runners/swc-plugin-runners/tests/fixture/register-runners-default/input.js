@@ -0,0 +1,4 @@
+export default async (ctx) => {
+  "use runner";
+  return { name: "smoke", status: "pass" };
+};
@@ -0,0 +1,18 @@
+/*__runners_transformed*/ const __runners_default = async (ctx)=>{
+    return {
+        name: "smoke",
+        status: "pass"
+    };
+};
+Object.defineProperty(__runners_default, "name", {
+    value: "default"
+});
+export default __runners_default;
+export const __runners = {
+    "input.js#default": {
+        fn: __runners_default,
+        name: "default",
+        file: "input.js",
+        line: 1
+    }
+};
@@ -0,0 +1,30 @@
+"use runner";
+
+import { z } from "zod";
+import type { Runner } from "@runners/core";
+
+export const TitleInput = z.object({ url: z.string() });
+
+export const checkTitle: Runner<typeof TitleInput> = async (ctx, input) => {
+  return { name: "checkTitle", status: "pass" };
+};
+
+export const fetchUserSchema = z.object({ id: z.string() });
+
+export async function fetchUser(ctx, input) {
+  return { name: "fetchUser", status: "pass" };
+}
+
+async function ping(ctx) {
+  return { name: "ping", status: "pass" };
+}
+
+export namespace Billing {
+  export const chargeInputSchema = z.object({ amount: z.number() });
+
+  export async function charge(ctx, input) {
+    return { name: "charge", status: "pass" };
+  }
+}
+
+export { ping, ping as healthCheck };
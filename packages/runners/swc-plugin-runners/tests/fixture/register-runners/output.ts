@@ -0,0 +1,73 @@
+/*__runners_transformed*/ import { z } from "zod";
+import type { Runner } from "@runners/core";
+export const TitleInput = z.object({
+    url: z.string()
+});
+export const checkTitle: Runner<typeof TitleInput> = async (ctx, input)=>{
+    return {
+        name: "checkTitle",
+        status: "pass"
+    };
+};
+export const fetchUserSchema = z.object({
+    id: z.string()
+});
+export async function fetchUser(ctx, input) {
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+}
+async function ping(ctx) {
+    return {
+        name: "ping",
+        status: "pass"
+    };
+}
+export namespace Billing {
+    export const chargeInputSchema = z.object({
+        amount: z.number()
+    });
+    export async function charge(ctx, input) {
+        return {
+            name: "charge",
+            status: "pass"
+        };
+    }
+}
+export { ping, ping as healthCheck };
+export const __runners = {
+    "input.ts#checkTitle": {
+        fn: checkTitle,
+        name: "checkTitle",
+        file: "input.ts",
+        line: 8,
+        inputSchema: TitleInput
+    },
+    "input.ts#fetchUser": {
+        fn: fetchUser,
+        name: "fetchUser",
+        file: "input.ts",
+        line: 14,
+        inputSchema: fetchUserSchema
+    },
+    "input.ts#ping": {
+        fn: ping,
+        name: "ping",
+        file: "input.ts",
+        line: 18
+    },
+    "input.ts#healthCheck": {
+        fn: ping,
+        name: "healthCheck",
+        file: "input.ts",
+        line: 18
+    },
+    "input.ts#Billing.charge": {
+        fn: Billing.charge,
+        name: "Billing.charge",
+        file: "input.ts",
+        line: 25,
+        inputSchema: Billing.chargeInputSchema
+    }
+};
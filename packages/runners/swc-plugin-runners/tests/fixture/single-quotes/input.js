@@ -0,0 +1,7 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */
+'use runner';
+
+export async function singleQuoted(_ctx) {
+  'use runner';
+  return { name: 'singleQuoted', status: 'pass' };
+}
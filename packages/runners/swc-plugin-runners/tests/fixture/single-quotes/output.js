@@ -0,0 +1,6 @@
+/** biome-ignore-all lint/suspicious/useAwait: false positive */ /*__runners_transformed*/ export async function singleQuoted(_ctx) {
+    return {
+        name: 'singleQuoted',
+        status: 'pass'
+    };
+}
@@ -0,0 +1,5 @@
+"use runner";
+
+exports.checkTitle = async (ctx) => {
+  return { name: "checkTitle", status: "pass" };
+};
@@ -0,0 +1,14 @@
+/*__runners_transformed*/ exports.checkTitle = async (ctx)=>{
+    return {
+        name: "checkTitle",
+        status: "pass"
+    };
+};
+Object.defineProperty(module.exports.checkTitle, "__runnerLocation", {
+    value: {
+        file: "input.cjs",
+        line: 3,
+        column: 1,
+        runnerId: "input.cjs#checkTitle"
+    }
+});
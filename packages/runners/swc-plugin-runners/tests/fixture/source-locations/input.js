@@ -0,0 +1,17 @@
+"use runner";
+
+export async function fetchUser(ctx) {
+  return { name: "fetchUser", status: "pass" };
+}
+
+const checkTitle = async (ctx) => ({ name: "checkTitle", status: "pass" });
+
+export { checkTitle, checkTitle as checkHeading };
+
+export default async function (ctx) {
+  return { name: "smoke", status: "pass" };
+}
+
+export const checkLink = defineRunner(
+  async (ctx) => ({ name: "checkLink", status: "pass" })
+);
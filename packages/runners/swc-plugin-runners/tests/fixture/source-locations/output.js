@@ -0,0 +1,88 @@
+/*__runners_transformed*/ export async function fetchUser(ctx) {
+    return {
+        name: "fetchUser",
+        status: "pass"
+    };
+}
+const checkTitle = async (ctx)=>({
+        name: "checkTitle",
+        status: "pass"
+    });
+export { checkTitle, checkTitle as checkHeading };
+export default async function __runners_default(ctx) {
+    return {
+        name: "smoke",
+        status: "pass"
+    };
+}
+Object.defineProperty(__runners_default, "name", {
+    value: "default"
+});
+export const checkLink = defineRunner(async (ctx)=>({
+        name: "checkLink",
+        status: "pass"
+    }));
+Object.defineProperty(fetchUser, "__runnerLocation", {
+    value: {
+        file: "input.js",
+        line: 3,
+        column: 8,
+        runnerId: "input.js#fetchUser"
+    }
+});
+Object.defineProperty(checkTitle, "__runnerLocation", {
+    value: {
+        file: "input.js",
+        line: 7,
+        column: 1,
+        runnerId: "input.js#checkTitle"
+    }
+});
+Object.defineProperty(__runners_default, "__runnerLocation", {
+    value: {
+        file: "input.js",
+        line: 11,
+        column: 16,
+        runnerId: "input.js#default"
+    }
+});
+Object.defineProperty(checkLink, "__runnerLocation", {
+    value: {
+        file: "input.js",
+        line: 15,
+        column: 8,
+        runnerId: "input.js#checkLink"
+    }
+});
+export const __runners = {
+    "input.js#fetchUser": {
+        fn: fetchUser,
+        name: "fetchUser",
+        file: "input.js",
+        line: 3
+    },
+    "input.js#checkTitle": {
+        fn: checkTitle,
+        name: "checkTitle",
+        file: "input.js",
+        line: 7
+    },
+    "input.js#checkHeading": {
+        fn: checkTitle,
+        name: "checkHeading",
+        file: "input.js",
+        line: 7
+    },
+    "input.js#default": {
+        fn: __runners_default,
+        name: "default",
+        file: "input.js",
+        line: 11
+    },
+    "input.js#checkLink": {
+        fn: checkLink,
+        name: "checkLink",
+        file: "input.js",
+        line: 15
+    }
+};
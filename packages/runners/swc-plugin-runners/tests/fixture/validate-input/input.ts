@@ -0,0 +1,21 @@
+"use runner";
+
+import { z } from "zod";
+import type { Runner } from "@runners/core";
+
+export const CheckTitleSchema = z.object({ url: z.string().url() });
+
+export const checkTitle: Runner<typeof CheckTitleSchema> = async (ctx, input) => {
+  const response = await fetch(input.url);
+  return { name: "checkTitle", status: response.ok ? "pass" : "fail" };
+};
+
+export const fetchUserInputSchema = z.object({ id: z.string() });
+
+export async function fetchUser(ctx, { id }) {
+  return { name: "fetchUser", status: "pass", details: { id } };
+}
+
+export async function ping(ctx) {
+  return { name: "ping", status: "pass" };
+}
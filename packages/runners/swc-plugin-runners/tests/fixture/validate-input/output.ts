@@ -0,0 +1,53 @@
+/*__runners_transformed*/ import { z } from "zod";
+import type { Runner } from "@runners/core";
+export const CheckTitleSchema = z.object({
+    url: z.string().url()
+});
+export const checkTitle: Runner<typeof CheckTitleSchema> = async (...__runnerArgs)=>__runners_validateInput("input.ts#checkTitle", CheckTitleSchema, async (ctx, input)=>{
+        const response = await fetch(input.url);
+        return {
+            name: "checkTitle",
+            status: response.ok ? "pass" : "fail"
+        };
+    })(...__runnerArgs);
+export const fetchUserInputSchema = z.object({
+    id: z.string()
+});
+export async function fetchUser(...__runnerArgs) {
+    return __runners_validateInput("input.ts#fetchUser", fetchUserInputSchema, async (ctx, { id })=>{
+        return {
+            name: "fetchUser",
+            status: "pass",
+            details: {
+                id
+            }
+        };
+    })(...__runnerArgs);
+}
+export async function ping(ctx) {
+    return {
+        name: "ping",
+        status: "pass"
+    };
+}
+function __runners_validateInput(runnerId, schema, run) {
+    return async (ctx, input, ...rest)=>{
+        if (typeof schema?.["~standard"]?.validate !== "function") {
+            return {
+                name: runnerId,
+                status: "error",
+                errorMessage: "Input schema is not a Standard Schema: it has no ~standard.validate function"
+            };
+        }
+        const result = await schema["~standard"].validate(input);
+        if (result.issues) {
+            const messages = result.issues.map((issue)=>issue.message).join(", ");
+            return {
+                name: runnerId,
+                status: "error",
+                errorMessage: `Validation failed: ${messages}`
+            };
+        }
+        return run(ctx, result.value, ...rest);
+    };
+}
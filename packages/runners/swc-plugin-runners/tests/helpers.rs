@@ -0,0 +1,46 @@
+//! The runtime helpers in `src/helpers/` are parsed by the plugin when a file
+//! first needs them, so each one must be a valid script declaring the single
+//! function the rewritten runners call.
+
+use std::{fs, path::PathBuf};
+use swc_core::{
+    common::{sync::Lrc, FileName, SourceMap},
+    ecma::{
+        ast::{Decl, EsVersion, Stmt},
+        parser::{parse_file_as_script, Syntax},
+    },
+};
+
+#[testing::fixture("src/helpers/*.js")]
+fn helper_test(input: PathBuf) {
+    let source = fs::read_to_string(&input).expect("helper should be readable");
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(Lrc::new(FileName::Real(input.clone())), source);
+
+    let mut errors = Vec::new();
+    let script = parse_file_as_script(
+        &fm,
+        Syntax::Es(Default::default()),
+        EsVersion::latest(),
+        None,
+        &mut errors,
+    )
+    .unwrap_or_else(|error| panic!("{} does not parse: {:?}", input.display(), error));
+    assert!(errors.is_empty(), "{} has syntax errors: {:?}", input.display(), errors);
+
+    let names: Vec<_> = script
+        .body
+        .iter()
+        .map(|stmt| match stmt {
+            Stmt::Decl(Decl::Fn(function)) => function.ident.sym.to_string(),
+            _ => panic!("{} should only declare a function", input.display()),
+        })
+        .collect();
+    assert_eq!(names.len(), 1, "{} should declare one function", input.display());
+    assert!(
+        names[0].starts_with("__runners_"),
+        "{} declares {}, which may clash with user code",
+        input.display(),
+        names[0]
+    );
+}
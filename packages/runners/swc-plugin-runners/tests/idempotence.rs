@@ -0,0 +1,111 @@
+//! Running `RunnerTransform` over its own output must change nothing.
+//!
+//! Some pipelines run SWC twice (dev server plus bundler) and
+//! `apply-swc-transform.ts` may retry, so every fixture is transformed,
+//! printed, parsed again and transformed a second time. The second run must
+//! leave the code as it is, must not add another manifest comment and must
+//! not report anything, not even the misplaced directives it leaves in place.
+//! The same holds when the transform is given no comments to write its
+//! markers to.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use swc_core::{
+    common::errors::{Handler, HANDLER},
+    ecma::{
+        ast::{Pass, Program},
+        parser::{Syntax, TsSyntax},
+        transforms::testing::Tester,
+        visit::visit_mut_pass,
+    },
+};
+use swc_plugin_runners::{RunnerTransform, WasmConfig};
+
+mod common;
+use common::CapturingEmitter;
+
+/// Transform `source` once, returning the printed output and the codes of
+/// the errors emitted along the way.
+fn transform(
+    input: &Path,
+    source: &str,
+    config: &WasmConfig,
+    with_comments: bool,
+) -> (String, Vec<String>) {
+    let file = input.file_name().unwrap().to_string_lossy().to_string();
+    let extension = input.extension().and_then(|extension| extension.to_str());
+
+    Tester::run(|tester| {
+        let mut program = match extension {
+            Some("cjs") => {
+                let syntax = Syntax::Es(Default::default());
+                Program::Script(tester.with_parser(&file, syntax, source, |p| p.parse_script())?)
+            }
+            Some("ts") => {
+                let syntax = Syntax::Typescript(TsSyntax::default());
+                Program::Module(tester.with_parser(&file, syntax, source, |p| p.parse_module())?)
+            }
+            _ => Program::Module(tester.parse_module(&file, source)?),
+        };
+
+        let emitter = CapturingEmitter::default();
+        let handler = Handler::with_emitter(true, false, Box::new(emitter.clone()));
+        let mut transform = RunnerTransform::new(file.clone())
+            .with_config(config.clone())
+            .with_source_map(tester.cm.clone());
+        if with_comments {
+            transform = transform.with_comments(tester.comments.clone());
+        }
+        let mut pass = visit_mut_pass(transform);
+        HANDLER.set(&handler, || pass.process(&mut program));
+
+        let code = tester.print(&program, &tester.comments.clone());
+        let errors = emitter.codes();
+        Ok((code, errors))
+    })
+}
+
+#[testing::fixture("tests/fixture/**/input.js")]
+#[testing::fixture("tests/fixture/**/input.cjs")]
+#[testing::fixture("tests/fixture/**/input.ts")]
+#[testing::fixture("tests/errors/**/input.js")]
+#[testing::fixture("tests/errors/**/input.cjs")]
+fn idempotence_test(input: PathBuf) {
+    assert_idempotent(&input, true);
+}
+
+#[testing::fixture("tests/fixture/**/input.js")]
+#[testing::fixture("tests/fixture/**/input.cjs")]
+#[testing::fixture("tests/fixture/**/input.ts")]
+#[testing::fixture("tests/errors/**/input.js")]
+#[testing::fixture("tests/errors/**/input.cjs")]
+fn idempotence_without_comments_test(input: PathBuf) {
+    assert_idempotent(&input, false);
+}
+
+fn assert_idempotent(input: &Path, with_comments: bool) {
+    let source = fs::read_to_string(input).expect("input should be readable");
+    let config_path = input.with_file_name("config.json");
+    let config: WasmConfig = match fs::read_to_string(config_path) {
+        Ok(json) => serde_json::from_str(&json).expect("config.json should be a valid plugin config"),
+        Err(_) => WasmConfig::default(),
+    };
+
+    let (once, _) = transform(input, &source, &config, with_comments);
+    let (twice, second_errors) = transform(input, &once, &config, with_comments);
+
+    assert_eq!(once, twice, "second run changed the output of {}", input.display());
+    assert!(
+        twice.matches("__internal_runners").count() <= 1,
+        "second run duplicated the manifest of {}",
+        input.display()
+    );
+    assert!(
+        second_errors.is_empty(),
+        "second run reported {:?} for {}",
+        second_errors,
+        input.display()
+    );
+}